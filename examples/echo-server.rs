@@ -10,7 +10,7 @@ mod err {
     pub type AnyResult<T> = Result<T, AnyError>;
 }
 
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::process::Termination;
 use std::thread;
@@ -22,12 +22,14 @@ const SERVER_IP: &str = "127.0.0.1";
 const SERVER_PORT: &str = "8080";
 
 fn main() -> impl Termination {
-    Report::capture(|| {
+    Report::capture(|| -> AnyResult<()> {
         let listener = TcpListener::bind(format!("{SERVER_IP}:{SERVER_PORT}"))
             .map_err(AnyError::wrap)
             .overlay("could not bind the listener to the endpoint")
             .context("ip", SERVER_IP)
-            .context("port", SERVER_PORT)?;
+            .context("port", SERVER_PORT)
+            .help(format!("check that port {SERVER_PORT} is free"))
+            .note("only one process can listen on the same endpoint at a time")?;
 
         eprintln!("Started listening on {SERVER_IP}:{SERVER_PORT}");
 
@@ -39,7 +41,7 @@ fn main() -> impl Termination {
             thread::spawn(move || {
                 handle_connection(stream).unwrap_or_else(|err| {
                     let report = Report::wrap(err).kind(false);
-                    eprintln!("{report}");
+                    let _ = report.write_to(&mut io::stderr().lock());
                 });
             });
         }
@@ -0,0 +1,326 @@
+mod future;
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::context::{AbstractContext, Context};
+use crate::converter::Convertable;
+
+pub use future::{ErrorContextFuture, WithErrorContext};
+
+type Applier<C> = Arc<dyn Fn(&mut C) + Send + Sync>;
+
+struct AmbientEntry {
+    scope: u64,
+    context_type: TypeId,
+    applier: Box<dyn Any>,
+}
+
+thread_local! {
+    static ENTRIES: RefCell<Vec<AmbientEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+static NEXT_SCOPE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Creates a new [`Scope`] whose ambient context is attached to errors with
+/// the context type `C`. See [`Scope`] for more information.
+pub fn scope<C: Context>() -> Scope<C> {
+    Scope::new()
+}
+
+/// A guard which attaches ambient context to errors created on the current
+/// thread while it's alive.
+///
+/// Context added to a [`Scope`] with [`Scope::context()`] is automatically
+/// inserted into every [`AnyError`] whose context type is `C`, as long as it's
+/// created from scratch on the same thread before the scope is dropped. This
+/// includes errors made by [`AnyError::minimal()`], [`AnyError::quick()`],
+/// [`AnyError::wrap()`] and the builder without a source, while wrapping an
+/// existing [`AnyError`] in a new layer doesn't duplicate the ambient context.
+/// The ambient context is inserted after the explicitly attached one.
+///
+/// Scopes can be nested, and each one only removes its own context when
+/// dropped. A [`Scope`] is neither [`Send`] nor [`Sync`] since the ambient
+/// context is stored in a thread-local storage.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::LiteralKeyStringMapContext;
+/// # use anyerr::kind::DefaultErrorKind;
+/// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+///
+/// fn handle() -> Result<(), AnyError> {
+///     Err(AnyError::minimal("could not handle the request"))
+/// }
+///
+/// {
+///     let _scope = AnyError::scope().context("request-id", 42);
+///     let err = handle().unwrap_err();
+///     assert_eq!(err.get("request-id"), Some("42"));
+/// }
+///
+/// let err = handle().unwrap_err();
+/// assert_eq!(err.get("request-id"), None);
+/// ```
+///
+/// [`AnyError`]: crate::AnyError
+/// [`AnyError::minimal()`]: crate::AnyError::minimal
+/// [`AnyError::quick()`]: crate::AnyError::quick
+/// [`AnyError::wrap()`]: crate::AnyError::wrap
+#[must_use = "the ambient context is removed as soon as the scope is dropped"]
+pub struct Scope<C: Context> {
+    id: u64,
+    _phantom: PhantomData<fn(&mut C)>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<C: Context> Scope<C> {
+    /// Creates a new [`Scope`] without any ambient context.
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_SCOPE_ID.fetch_add(1, Ordering::Relaxed),
+            _phantom: Default::default(),
+            _not_send: Default::default(),
+        }
+    }
+
+    /// Adds some ambient context represented as a key-value pair, which is
+    /// converted and inserted into each error created within this scope.
+    pub fn context<Q, R>(self, key: Q, value: R) -> Self
+    where
+        Q: Into<C::Key> + Clone + Send + Sync + 'static,
+        R: Convertable<C::Converter, C::Value> + Clone + Send + Sync + 'static,
+    {
+        self.push(applier(key, value));
+        self
+    }
+
+    fn push(&self, applier: Applier<C>) {
+        ENTRIES.with_borrow_mut(|entries| {
+            entries.push(AmbientEntry {
+                scope: self.id,
+                context_type: TypeId::of::<C>(),
+                applier: Box::new(applier),
+            })
+        });
+    }
+}
+
+impl<C: Context> Default for Scope<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Context> Debug for Scope<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Scope").field("id", &self.id).finish()
+    }
+}
+
+impl<C: Context> Drop for Scope<C> {
+    fn drop(&mut self) {
+        // The thread-local storage may have been destroyed if the scope lives
+        // in another thread-local value, in which case there's nothing left
+        // to remove.
+        let _ =
+            ENTRIES.try_with(|entries| entries.borrow_mut().retain(|entry| entry.scope != self.id));
+    }
+}
+
+/// A set of ambient context which isn't bound to a thread.
+///
+/// Unlike a [`Scope`], an [`AmbientContext`] only holds the ambient context
+/// and can be sent across threads, so that it can be installed on whichever
+/// thread it's needed, either by [`AmbientContext::enter()`] or by the future
+/// combinator [`WithErrorContext::with_error_context()`].
+pub struct AmbientContext<C: Context> {
+    appliers: Vec<Applier<C>>,
+}
+
+impl<C: Context> AmbientContext<C> {
+    /// Creates an empty [`AmbientContext`].
+    pub fn new() -> Self {
+        Self {
+            appliers: Vec::new(),
+        }
+    }
+
+    /// Adds some ambient context represented as a key-value pair.
+    pub fn context<Q, R>(mut self, key: Q, value: R) -> Self
+    where
+        Q: Into<C::Key> + Clone + Send + Sync + 'static,
+        R: Convertable<C::Converter, C::Value> + Clone + Send + Sync + 'static,
+    {
+        self.appliers.push(applier(key, value));
+        self
+    }
+
+    /// Returns the number of key-value pairs held by this [`AmbientContext`].
+    pub fn len(&self) -> usize {
+        self.appliers.len()
+    }
+
+    /// Returns `true` if this [`AmbientContext`] holds nothing.
+    pub fn is_empty(&self) -> bool {
+        self.appliers.is_empty()
+    }
+
+    /// Installs the ambient context on the current thread until the returned
+    /// [`Scope`] is dropped.
+    pub fn enter(&self) -> Scope<C> {
+        let scope = Scope::new();
+        for applier in &self.appliers {
+            scope.push(Arc::clone(applier));
+        }
+        scope
+    }
+}
+
+impl<C: Context> Clone for AmbientContext<C> {
+    fn clone(&self) -> Self {
+        Self {
+            appliers: self.appliers.clone(),
+        }
+    }
+}
+
+impl<C: Context> Default for AmbientContext<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Context> Debug for AmbientContext<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AmbientContext")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+fn applier<C, Q, R>(key: Q, value: R) -> Applier<C>
+where
+    C: Context,
+    Q: Into<C::Key> + Clone + Send + Sync + 'static,
+    R: Convertable<C::Converter, C::Value> + Clone + Send + Sync + 'static,
+{
+    Arc::new(move |context: &mut C| {
+        context.insert_with::<C::Converter, _, _>(key.clone(), value.clone());
+    })
+}
+
+/// Makes a new context of type `C` with the ambient context of the current
+/// thread inserted.
+pub(crate) fn context<C: AbstractContext>() -> C {
+    let mut context = C::default();
+    apply(&mut context);
+    context
+}
+
+/// Inserts the ambient context of the current thread into `context`.
+pub(crate) fn apply<C: AbstractContext>(context: &mut C) {
+    let appliers = ENTRIES
+        .try_with(|entries| {
+            entries
+                .borrow()
+                .iter()
+                .filter(|entry| entry.context_type == TypeId::of::<C>())
+                .filter_map(|entry| entry.applier.downcast_ref::<Applier<C>>())
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    // Appliers are called after the storage is released, since converting
+    // values may create errors on its own.
+    appliers.iter().for_each(|applier| applier(context));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::thread;
+
+    use crate::context::{Entry, LiteralKeyStringMapContext, StringKeyStringMapContext};
+    use crate::core::ContextDepth;
+    use crate::kind::DefaultErrorKind;
+    use crate::{AnyError, Intermediate, Overlay};
+
+    use super::*;
+
+    type TestError = AnyError<LiteralKeyStringMapContext, DefaultErrorKind>;
+    type OtherError = AnyError<StringKeyStringMapContext, DefaultErrorKind>;
+
+    #[test]
+    fn scope_context_is_attached_to_new_errors() {
+        let _scope = TestError::scope()
+            .context("request-id", 42)
+            .context("tenant", "test");
+
+        let err = TestError::minimal("error");
+        assert_eq!(err.get("request-id"), Some("42"));
+        assert_eq!(err.get("tenant"), Some("\"test\""));
+
+        let err = TestError::quick("error", DefaultErrorKind::Unknown);
+        assert_eq!(err.get("request-id"), Some("42"));
+
+        let err = TestError::builder()
+            .message("error")
+            .context("key", 1)
+            .build();
+        let keys = err
+            .context(ContextDepth::All)
+            .map(|entry| entry.key())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["key", "request-id", "tenant"]);
+
+        let err = TestError::wrap("".parse::<u32>().unwrap_err());
+        assert_eq!(err.get("request-id"), Some("42"));
+
+        let err = err.overlay("outer").build();
+        assert_eq!(err.get("request-id"), None);
+        assert_eq!(err.context(ContextDepth::All).count(), 2);
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn scope_context_is_removed_when_scope_is_dropped() {
+        let outer = scope::<LiteralKeyStringMapContext>().context("outer", 1);
+        {
+            let _inner = scope::<LiteralKeyStringMapContext>().context("inner", 2);
+            let err = TestError::minimal("error");
+            assert_eq!(err.get("outer"), Some("1"));
+            assert_eq!(err.get("inner"), Some("2"));
+        }
+        let err = TestError::minimal("error");
+        assert_eq!(err.get("outer"), Some("1"));
+        assert_eq!(err.get("inner"), None);
+
+        drop(outer);
+        let err = TestError::minimal("error");
+        assert_eq!(err.context(ContextDepth::All).count(), 0);
+    }
+
+    #[test]
+    fn scope_context_is_isolated() {
+        let _scope = TestError::scope().context("key", 1);
+
+        let err = OtherError::minimal("error");
+        assert_eq!(err.get("key"), None);
+
+        let count = thread::spawn(|| {
+            TestError::minimal("error")
+                .context(ContextDepth::All)
+                .count()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(count, 0);
+    }
+}
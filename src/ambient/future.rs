@@ -0,0 +1,164 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context as TaskContext, Poll};
+
+use crate::ambient::AmbientContext;
+use crate::context::Context;
+
+/// An extension trait which installs ambient context for futures.
+///
+/// Since an asynchronous task may be moved between the threads of an executor,
+/// a [`Scope`] created inside it can't reliably attach context to the errors
+/// it makes. [`WithErrorContext::with_error_context()`] solves this by
+/// installing the given [`AmbientContext`] on the current thread during each
+/// poll of the future, no matter which runtime drives it.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::future::Future;
+/// # use std::pin::pin;
+/// # use std::sync::Arc;
+/// # use std::task::{Context, Poll, Wake, Waker};
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::ambient::WithErrorContext;
+/// # use anyerr::context::LiteralKeyStringMapContext;
+/// # use anyerr::kind::DefaultErrorKind;
+/// # struct NoopWaker;
+/// # impl Wake for NoopWaker {
+/// #     fn wake(self: Arc<Self>) {}
+/// # }
+/// # // The futures below never wait, so polling them once is enough.
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     let waker = Waker::from(Arc::new(NoopWaker));
+/// #     match pin!(fut).poll(&mut Context::from_waker(&waker)) {
+/// #         Poll::Ready(output) => output,
+/// #         Poll::Pending => unreachable!(),
+/// #     }
+/// # }
+/// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+///
+/// async fn handle() -> Result<(), AnyError> {
+///     Err(AnyError::minimal("could not handle the request"))
+/// }
+///
+/// let context = AnyError::ambient_context().context("request-id", 42);
+/// let err = block_on(handle().with_error_context(context)).unwrap_err();
+/// assert_eq!(err.get("request-id"), Some("42"));
+/// ```
+///
+/// [`Scope`]: crate::ambient::Scope
+pub trait WithErrorContext: Future + Sized {
+    /// Wraps this future so that `context` is installed as the ambient context
+    /// whenever it's polled.
+    fn with_error_context<C: Context>(
+        self,
+        context: AmbientContext<C>,
+    ) -> ErrorContextFuture<Self, C>;
+}
+
+impl<F: Future> WithErrorContext for F {
+    fn with_error_context<C: Context>(
+        self,
+        context: AmbientContext<C>,
+    ) -> ErrorContextFuture<Self, C> {
+        ErrorContextFuture {
+            inner: self,
+            context,
+        }
+    }
+}
+
+/// A future returned by [`WithErrorContext::with_error_context()`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ErrorContextFuture<F, C: Context> {
+    inner: F,
+    context: AmbientContext<C>,
+}
+
+impl<F, C: Context> ErrorContextFuture<F, C> {
+    /// Returns the ambient context installed by this future.
+    pub fn context(&self) -> &AmbientContext<C> {
+        &self.context
+    }
+
+    /// Consumes this future and returns the wrapped one.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl<F: Future, C: Context> Future for ErrorContextFuture<F, C> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned. It's never moved out of a
+        // pinned `ErrorContextFuture`, which implements neither `Drop` nor
+        // `Unpin` by hand, while `context` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+        let _scope = this.context.enter();
+        inner.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::thread;
+
+    use crate::context::LiteralKeyStringMapContext;
+    use crate::kind::DefaultErrorKind;
+    use crate::test_util::poll_once;
+    use crate::AnyError;
+
+    use super::*;
+
+    type TestError = AnyError<LiteralKeyStringMapContext, DefaultErrorKind>;
+
+    /// A future which yields once before making an error.
+    struct YieldThenFail {
+        yielded: bool,
+    }
+
+    impl Future for YieldThenFail {
+        type Output = TestError;
+
+        fn poll(mut self: Pin<&mut Self>, _cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+            if self.yielded {
+                Poll::Ready(TestError::minimal("error"))
+            } else {
+                self.yielded = true;
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn error_context_future_installs_context_during_polls() {
+        let context = TestError::ambient_context().context("request-id", 42);
+        let mut fut = pin!(async { TestError::minimal("error") }.with_error_context(context));
+        let Poll::Ready(err) = poll_once(&mut fut) else {
+            panic!("the future should be ready");
+        };
+        assert_eq!(err.get("request-id"), Some("42"));
+        assert_eq!(TestError::minimal("error").get("request-id"), None);
+    }
+
+    #[test]
+    fn error_context_future_succeeds_across_threads() {
+        let context = TestError::ambient_context().context("request-id", 42);
+        let mut fut = YieldThenFail { yielded: false }.with_error_context(context);
+        assert!(poll_once(&mut fut).is_pending());
+        assert_eq!(TestError::minimal("error").get("request-id"), None);
+
+        let err = thread::spawn(move || match poll_once(&mut fut) {
+            Poll::Ready(err) => err,
+            Poll::Pending => panic!("the future should be ready"),
+        })
+        .join()
+        .unwrap();
+        assert_eq!(err.get("request-id"), Some("42"));
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::btree_map::{BTreeMap, Values};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use crate::context::Value;
+
+/// A stable code identifying a specific kind of error, such as `E1042`, along
+/// with a short description and an optional link to further help.
+///
+/// Codes can be attached to error kinds through [`Kind::error_code()`], or to
+/// individual error sites through [`AnyErrorBuilder::error_code()`], and are
+/// collected into a [`Catalog`] for documentation purposes.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::code::ErrorCode;
+/// const E1042: ErrorCode = ErrorCode::new("E1042", "the account is locked")
+///     .with_help_url("https://example.com/errors/E1042");
+/// assert_eq!(E1042.code(), "E1042");
+/// assert_eq!(E1042.to_string(), "E1042");
+/// ```
+///
+/// [`Kind::error_code()`]: crate::kind::Kind::error_code
+/// [`AnyErrorBuilder::error_code()`]: crate::core::AnyErrorBuilder::error_code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrorCode {
+    code: &'static str,
+    description: &'static str,
+    help_url: Option<&'static str>,
+}
+
+impl ErrorCode {
+    /// Creates an [`ErrorCode`] with the given code and description.
+    pub const fn new(code: &'static str, description: &'static str) -> Self {
+        Self {
+            code,
+            description,
+            help_url: None,
+        }
+    }
+
+    /// Sets the URL of the page which explains the error in detail.
+    pub const fn with_help_url(self, help_url: &'static str) -> Self {
+        Self {
+            help_url: Some(help_url),
+            ..self
+        }
+    }
+
+    /// Returns the code itself, such as `E1042`.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the short description of the error.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the URL of the page which explains the error in detail.
+    pub const fn help_url(&self) -> Option<&'static str> {
+        self.help_url
+    }
+
+    fn to_value(self) -> Value {
+        let mut map = BTreeMap::new();
+        map.insert("code".into(), self.code.into());
+        map.insert("description".into(), self.description.into());
+        map.insert("help_url".into(), self.help_url.into());
+        Value::Map(map)
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.code)
+    }
+}
+
+/// A collection of [`ErrorCode`]s sorted by code, which can be exported as
+/// Markdown or JSON so that documentation stays in sync with the code.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::code::{Catalog, ErrorCode};
+/// let catalog = Catalog::new()
+///     .register(ErrorCode::new("E1042", "the account is locked"))
+///     .register(
+///         ErrorCode::new("E1001", "the password is wrong")
+///             .with_help_url("https://example.com/errors/E1001"),
+///     );
+/// assert_eq!(catalog.len(), 2);
+/// assert_eq!(catalog.get("E1042").unwrap().description(), "the account is locked");
+/// assert_eq!(
+///     catalog.to_json(),
+///     concat!(
+///         r#"[{"code":"E1001","description":"the password is wrong","#,
+///         r#""help_url":"https://example.com/errors/E1001"},"#,
+///         r#"{"code":"E1042","description":"the account is locked","help_url":null}]"#,
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    codes: BTreeMap<&'static str, ErrorCode>,
+}
+
+impl Catalog {
+    /// Creates an empty [`Catalog`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `code` to the catalog, replacing any code registered before with
+    /// the same name.
+    pub fn register(mut self, code: ErrorCode) -> Self {
+        self.insert(code);
+        self
+    }
+
+    /// Adds `code` to the catalog and returns the code registered before
+    /// with the same name if there's one.
+    pub fn insert(&mut self, code: ErrorCode) -> Option<ErrorCode> {
+        self.codes.insert(code.code, code)
+    }
+
+    /// Returns the registered code named `code`.
+    pub fn get(&self, code: &str) -> Option<&ErrorCode> {
+        self.codes.get(code)
+    }
+
+    /// Returns the number of registered codes.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    /// Returns true if no code is registered.
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Returns an iterator over the registered codes, sorted by code.
+    pub fn iter(&self) -> Values<'_, &'static str, ErrorCode> {
+        self.codes.values()
+    }
+
+    /// Renders the catalog as a Markdown table with a row for each code.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::from("| Code | Description | Help |\n| --- | --- | --- |\n");
+        for code in self.iter() {
+            let help = code
+                .help_url
+                .map(|url| format!("<{url}>"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                output,
+                "| `{}` | {} | {} |",
+                code.code,
+                code.description.replace('|', "\\|"),
+                help,
+            );
+        }
+        output
+    }
+
+    /// Collects all codes into a [`Value::List`], each of which is a
+    /// [`Value::Map`] with the keys `code`, `description` and `help_url`.
+    pub fn to_value(&self) -> Value {
+        Value::List(self.iter().map(|code| code.to_value()).collect())
+    }
+
+    /// Returns the JSON representation of the catalog.
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+}
+
+impl<'a> IntoIterator for &'a Catalog {
+    type Item = &'a ErrorCode;
+    type IntoIter = Values<'a, &'static str, ErrorCode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<ErrorCode> for Catalog {
+    fn from_iter<T: IntoIterator<Item = ErrorCode>>(iter: T) -> Self {
+        let mut catalog = Self::new();
+        catalog.extend(iter);
+        catalog
+    }
+}
+
+impl Extend<ErrorCode> for Catalog {
+    fn extend<T: IntoIterator<Item = ErrorCode>>(&mut self, iter: T) {
+        for code in iter {
+            self.insert(code);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKED: ErrorCode = ErrorCode::new("E1042", "the account is locked")
+        .with_help_url("https://example.com/errors/E1042");
+    const WRONG_PASSWORD: ErrorCode = ErrorCode::new("E1001", "the password | PIN is wrong");
+
+    #[test]
+    fn catalog_register_succeeds() {
+        let mut catalog: Catalog = [LOCKED, WRONG_PASSWORD].into_iter().collect();
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(
+            catalog.iter().map(ErrorCode::code).collect::<Vec<_>>(),
+            ["E1001", "E1042"]
+        );
+
+        let replaced = catalog.insert(ErrorCode::new("E1042", "the account is frozen"));
+        assert_eq!(replaced, Some(LOCKED));
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(
+            catalog.get("E1042").map(ErrorCode::description),
+            Some("the account is frozen")
+        );
+        assert_eq!(catalog.get("E9999"), None);
+    }
+
+    #[test]
+    fn catalog_to_markdown_succeeds() {
+        let catalog = Catalog::new().register(LOCKED).register(WRONG_PASSWORD);
+        assert_eq!(
+            catalog.to_markdown(),
+            concat!(
+                "| Code | Description | Help |\n",
+                "| --- | --- | --- |\n",
+                "| `E1001` | the password \\| PIN is wrong |  |\n",
+                "| `E1042` | the account is locked | <https://example.com/errors/E1042> |\n",
+            )
+        );
+        assert_eq!(
+            Catalog::new().to_markdown(),
+            "| Code | Description | Help |\n| --- | --- | --- |\n"
+        );
+    }
+
+    #[test]
+    fn catalog_to_json_succeeds() {
+        let catalog = Catalog::new().register(LOCKED);
+        assert_eq!(
+            catalog.to_json(),
+            concat!(
+                r#"[{"code":"E1042","description":"the account is locked","#,
+                r#""help_url":"https://example.com/errors/E1042"}]"#
+            )
+        );
+        assert_eq!(Catalog::new().to_json(), "[]");
+    }
+}
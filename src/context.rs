@@ -1,21 +1,42 @@
 pub mod any;
+pub mod hash;
+pub mod inline;
 pub mod iter;
 pub mod map;
+pub mod policy;
+pub mod redacted;
 pub mod singleton;
+pub mod sorted;
 pub mod unit;
+pub mod value;
 
-use std::any::Any;
-use std::borrow::Borrow;
-use std::fmt::{Debug, Display};
+mod index;
+
+use std::any::{Any, TypeId};
+use std::borrow::{Borrow, Cow};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
 
 use crate::converter::{Convertable, Converter};
 
-pub use any::{AnyValue, DynAnyValue};
-pub use map::{LiteralKeyAnyMapContext, StringKeyAnyMapContext};
+pub use any::{AnyValue, Displayed, DynAnyValue};
+pub use hash::{LiteralKeyAnyHashMapContext, StringKeyAnyHashMapContext};
+pub use hash::{LiteralKeyStringHashMapContext, StringKeyStringHashMapContext};
+pub use inline::{CowKeyCowStringInlineMapContext, StringKeyStringInlineMapContext};
+pub use inline::{LiteralKeyAnyInlineMapContext, StringKeyAnyInlineMapContext};
+pub use inline::{LiteralKeyCowStringInlineMapContext, LiteralKeyStringInlineMapContext};
+pub use map::{CowKeyAnyMapContext, LiteralKeyAnyMapContext, StringKeyAnyMapContext};
+pub use map::{CowKeyCowStringMapContext, CowKeyStringMapContext, LiteralKeyCowStringMapContext};
+pub use map::{LiteralKeyDisplayStringMapContext, StringKeyDisplayStringMapContext};
 pub use map::{LiteralKeyStringMapContext, StringKeyStringMapContext};
+pub use map::{LiteralKeyValueMapContext, StringKeyValueMapContext};
+pub use policy::{DuplicatePolicy, KeepAll, KeepFirst, MergePolicy, MergedContext, Replace};
+pub use redacted::Redacted;
 pub use singleton::{AnySingletonContext, FixedSingletonContext, StringSingletonContext};
+pub use sorted::{LiteralKeyAnySortedMapContext, StringKeyAnySortedMapContext};
+pub use sorted::{LiteralKeyStringSortedMapContext, StringKeyStringSortedMapContext};
 pub use unit::UnitContext;
+pub use value::Value;
 
 /// The most fundamental trait of all context storage.
 ///
@@ -127,7 +148,8 @@ pub trait SingletonContext: Context {
     fn value(&self) -> Option<&<Self::Entry as Entry>::ValueBorrowed>;
 }
 
-/// The context where each entry's value is a [`String`].
+/// The context where each entry's value is a string, typically a [`String`]
+/// or a [`Cow<'static, str>`].
 ///
 /// For contexts implementing this trait, refer to the [`crate::context::map`]
 /// module.
@@ -144,9 +166,11 @@ pub trait SingletonContext: Context {
 /// assert_eq!(context.get("i32"), Some("42"));
 /// assert_eq!(context.get("&str"), Some("\"context\""));
 /// ```
+///
+/// [`Cow<'static, str>`]: std::borrow::Cow
 pub trait StringContext
 where
-    Self: Context<Value = String, Entry: Entry<ValueBorrowed = str>>,
+    Self: Context<Entry: Entry<ValueBorrowed = str>>,
 {
 }
 
@@ -178,13 +202,58 @@ where
     /// Returns the value corresponding to the given key and tries to cast it
     /// to the type `T`. Returns `None` if the entry doesn't exist or the
     /// downcasting fails.
+    ///
+    /// A value wrapped in [`Redacted<T>`] or [`Displayed<T>`] can be cast to
+    /// either `T` or the wrapper itself.
     fn value_as<T, Q>(&self, key: &Q) -> Option<&T>
     where
         <Self::Entry as Entry>::KeyBorrowed: Borrow<Q>,
         Q: Debug + Eq + Hash + ?Sized,
         T: Any,
     {
-        self.get(key).and_then(|value| value.downcast_ref::<T>())
+        self.get(key).and_then(|value| {
+            value
+                .downcast_ref::<T>()
+                .or_else(|| value.downcast_ref::<Redacted<T>>().map(Redacted::expose))
+                .or_else(|| value.downcast_ref::<Displayed<T>>().map(Displayed::value))
+        })
+    }
+}
+
+/// The context where each entry's value is a structured [`Value`].
+///
+/// Since a [`Value`] keeps the structure of the original data, the whole
+/// context can be turned into a [`Value::Map`] and serialized as JSON.
+///
+/// For contexts implementing this trait, refer to the [`crate::context::map`]
+/// module.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::context::{Context, LiteralKeyValueMapContext, Value, ValueContext};
+/// # use anyerr::converter::ValueConverter;
+/// // `LiteralKeyValueMapContext` implements `ValueContext`.
+/// let mut context = LiteralKeyValueMapContext::new();
+/// context.insert_with::<ValueConverter, _, _>("error-code", 42);
+/// context.insert_with::<ValueConverter, _, _>("causes", vec!["unknown"]);
+/// assert_eq!(context.get("error-code"), Some(&Value::I64(42)));
+/// assert_eq!(context.to_json(), r#"{"causes":["unknown"],"error-code":42}"#);
+/// ```
+pub trait ValueContext
+where
+    Self: Context<Entry: Entry<ValueBorrowed = Value>>,
+{
+    /// Collects all entries into a [`Value::Map`] whose keys are the
+    /// [`Display`] representations of the entries' keys. If multiple entries
+    /// share the same key, the first one is kept.
+    fn to_value(&self) -> Value {
+        MergePolicy::OutermostWins.merge(self.iter()).to_value()
+    }
+
+    /// Returns the JSON representation of all entries.
+    fn to_json(&self) -> String {
+        self.to_value().to_json()
     }
 }
 
@@ -213,6 +282,93 @@ pub trait Entry: Debug + Send + Sync + 'static {
 
     /// Returns the entry's value.
     fn value(&self) -> &Self::ValueBorrowed;
+
+    /// Writes the entry as it's presented in error reports, including the
+    /// [`Debug`] output of [`AnyError`].
+    ///
+    /// The default implementation writes the key followed by the [`Debug`]
+    /// representation of the value, such as `key = 42`. An entry which also
+    /// implements [`Display`] should override this method to produce the same
+    /// output, so that reports look the same no matter how they're printed.
+    ///
+    /// [`AnyError`]: crate::AnyError
+    fn fmt_entry(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} = {:?}", self.key(), self.value())
+    }
+
+    /// Converts the entry's value into a [`Value`] for structured output,
+    /// such as the events emitted by `AnyError::record()` with the `tracing`
+    /// feature enabled.
+    ///
+    /// The default implementation produces a [`Value::String`] holding the
+    /// [`Debug`] representation of the value. The built-in entries keep a
+    /// [`Value`] as it is and a string value without quoting it again.
+    fn structured_value(&self) -> Value {
+        Value::String(format!("{:?}", self.value()))
+    }
+}
+
+/// The view of an entry's value which decides how the built-in entries
+/// present it.
+enum ValueView<'a, VB: ?Sized> {
+    /// A string value, which has been formatted on insertion.
+    Text(&'a str),
+    /// A structured value.
+    Value(&'a Value),
+    /// Any other value, which is presented with its [`Debug`] representation.
+    Other(&'a VB),
+}
+
+impl<'a, VB: Debug + ?Sized + 'static> ValueView<'a, VB> {
+    fn new<V>(value: &'a V) -> Self
+    where
+        V: Borrow<VB> + Debug + 'static,
+    {
+        let any: &dyn Any = value;
+        if TypeId::of::<VB>() == TypeId::of::<str>() {
+            let text = any
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| any.downcast_ref::<Cow<'static, str>>().map(|text| &**text))
+                .or_else(|| any.downcast_ref::<&'static str>().copied());
+            if let Some(text) = text {
+                return Self::Text(text);
+            }
+        }
+        match any.downcast_ref::<Value>() {
+            Some(value) => Self::Value(value),
+            None => Self::Other(value.borrow()),
+        }
+    }
+}
+
+/// Writes an entry's value in the way the built-in entries present it: a
+/// string value is written as it is, a [`Value`] is written as JSON, and any
+/// other value is written with its [`Debug`] representation.
+pub(crate) fn fmt_value<V, VB>(value: &V, f: &mut Formatter<'_>) -> FmtResult
+where
+    V: Borrow<VB> + Debug + 'static,
+    VB: Debug + ?Sized + 'static,
+{
+    match ValueView::<VB>::new(value) {
+        ValueView::Text(text) => f.write_str(text),
+        ValueView::Value(value) => Display::fmt(value, f),
+        ValueView::Other(value) => Debug::fmt(value, f),
+    }
+}
+
+/// Converts an entry's value into a [`Value`] in the way the built-in entries
+/// do, which is consistent with [`fmt_value()`].
+pub(crate) fn structured_value<V, VB>(value: &V) -> Value
+where
+    V: Borrow<VB> + Debug + 'static,
+    VB: Debug + ?Sized + 'static,
+{
+    match ValueView::<VB>::new(value) {
+        ValueView::Text(text) => Value::String(text.into()),
+        ValueView::Value(value) => value.clone(),
+        ValueView::Other(value) => Value::String(format!("{value:?}")),
+    }
 }
 
 /// A dedicated iterator of the context storage.
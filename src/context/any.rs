@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 /// A type alias of the trait object of the [`AnyValue`] trait.
 pub type DynAnyValue = dyn AnyValue + Send + Sync + 'static;
@@ -34,6 +34,92 @@ impl dyn AnyValue + Send + Sync + 'static {
     }
 }
 
+/// A wrapper which captures the [`Display`] representation of a value when
+/// it's created.
+///
+/// Values stored in an [`AnyContext`] are presented with their [`Debug`]
+/// representations in error reports. Wrapping a value in a [`Displayed`]
+/// before attaching it makes reports show the value the way a user would
+/// type it instead, while the value itself is still reachable through
+/// [`AnyContext::value_as()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::{Displayed, LiteralKeyAnyMapContext};
+/// # use anyerr::kind::DefaultErrorKind;
+/// # use anyerr::report::Report;
+/// type AnyError = AnyErrorTemplate<LiteralKeyAnyMapContext, DefaultErrorKind>;
+///
+/// let err = AnyError::builder()
+///     .message("could not open the file")
+///     .context("path", Displayed::new(String::from("/tmp/a b")))
+///     .build();
+/// assert_eq!(
+///     err.value_as::<String, _>("path").map(String::as_str),
+///     Some("/tmp/a b")
+/// );
+///
+/// let report = Report::wrap(err).pretty(false);
+/// assert_eq!(
+///     report.to_string(),
+///     "(Unknown) could not open the file [path = /tmp/a b]"
+/// );
+/// ```
+///
+/// [`AnyContext`]: crate::context::AnyContext
+/// [`AnyContext::value_as()`]: crate::context::AnyContext::value_as
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Displayed<T> {
+    value: T,
+    display: String,
+}
+
+impl<T: Display> Displayed<T> {
+    /// Wraps the `value` in a [`Displayed`] and captures its [`Display`]
+    /// representation.
+    pub fn new(value: T) -> Self {
+        let display = value.to_string();
+        Self { value, display }
+    }
+}
+
+impl<T> Displayed<T> {
+    /// Returns a reference to the wrapped value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the captured [`Display`] representation.
+    pub fn as_str(&self) -> &str {
+        &self.display
+    }
+
+    /// Consumes the [`Displayed`] and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Display> From<T> for Displayed<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Debug for Displayed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.display)
+    }
+}
+
+impl<T> Display for Displayed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.display)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,6 +131,15 @@ mod tests {
         assert_eq!(x.downcast_ref::<String>().unwrap(), "any value");
     }
 
+    #[test]
+    fn displayed_format_succeeds() {
+        let x: Box<dyn AnyValue + Send + Sync + 'static> = Box::new(Displayed::new("text"));
+        assert_eq!(format!("{x:?}"), "text");
+        let x = x.downcast_ref::<Displayed<&str>>().unwrap();
+        assert_eq!(*x.value(), "text");
+        assert_eq!(x.as_str(), "text");
+    }
+
     #[test]
     fn any_value_debug_succeeds() {
         let x: Box<dyn AnyValue + Send + Sync + 'static> = Box::new(String::from("any value"));
@@ -0,0 +1,268 @@
+mod facade;
+
+pub use facade::*;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::context::index::KeyIndex;
+use crate::context::map::MapIter;
+use crate::context::policy::{DuplicatePolicy, Replace};
+use crate::context::{AbstractContext, Context, Entry};
+use crate::converter::Converter;
+
+/// A map-like context which looks up entries through a hash index.
+///
+/// Unlike [`MapContext`] which scans all entries for each lookup,
+/// [`HashMapContext`] finds an entry in constant time on average, which pays
+/// off for errors carrying dozens of entries. Entries are iterated in the
+/// order they're inserted, so reports are stable across runs.
+///
+/// Inserting an entry whose key already exists is handled by the
+/// [`DuplicatePolicy`] `P`, which replaces the existing value by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::context::{Context, KeepFirst, LiteralKeyStringHashMapContext};
+/// let mut context = LiteralKeyStringHashMapContext::<KeepFirst>::new();
+/// context.insert("key", "1");
+/// context.insert("key", "2");
+/// assert_eq!(context.get("key"), Some("1"));
+/// ```
+///
+/// [`MapContext`]: crate::context::map::MapContext
+pub struct HashMapContext<E, C, P = Replace>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    entries: Vec<E>,
+    index: KeyIndex,
+    _phantom: PhantomData<(C, P)>,
+}
+
+impl<E, C, P> HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    /// Creates a new [`HashMapContext`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: KeyIndex::default(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns an iterator over all values corresponding to the given key,
+    /// which yields more than one value only if `P` is [`KeepAll`].
+    ///
+    /// [`KeepAll`]: crate::context::policy::KeepAll
+    pub fn get_all<'a, Q>(&'a self, key: &'a Q) -> impl Iterator<Item = &'a E::ValueBorrowed>
+    where
+        E::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        let hash = self.index.hash(key);
+        self.index
+            .find(&self.entries, hash, key)
+            .map(|position| self.entries[position].value())
+    }
+
+    fn insert_entry(&mut self, entry: E) {
+        let hash = self.index.hash(entry.key());
+        let existing = self.index.find(&self.entries, hash, entry.key()).next();
+        let entry = match existing {
+            Some(position) => P::resolve(&mut self.entries[position], entry),
+            None => Some(entry),
+        };
+        if let Some(entry) = entry {
+            self.index.insert(hash, self.entries.len());
+            self.entries.push(entry);
+        }
+    }
+}
+
+impl<E, C, P> From<Vec<E>> for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn from(entries: Vec<E>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E, C, P, Q, R> From<Vec<(Q, R)>> for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from(entries: Vec<(Q, R)>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E, C, P> FromIterator<E> for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut context = Self::new();
+        iter.into_iter()
+            .for_each(|entry| context.insert_entry(entry));
+        context
+    }
+}
+
+impl<E, C, P, Q, R> FromIterator<(Q, R)> for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from_iter<T: IntoIterator<Item = (Q, R)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(|(key, value)| E::new(key.into(), value.into()))
+            .collect()
+    }
+}
+
+impl<E, C, P> Default for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, C, P> Debug for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("HashMapContext")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl<E, C, P> AbstractContext for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    type Key = E::Key;
+
+    type Value = E::Value;
+
+    type Entry = E;
+
+    type Iter<'a>
+        = MapIter<'a, E>
+    where
+        E: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.entries.iter().into()
+    }
+}
+
+impl<E, C, P> Context for HashMapContext<E, C, P>
+where
+    E: Entry,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    type Converter = C;
+
+    fn insert<Q, R>(&mut self, key: Q, value: R)
+    where
+        Q: Into<Self::Key>,
+        R: Into<Self::Value>,
+    {
+        self.insert_entry(Self::Entry::new(key, value));
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&<Self::Entry as Entry>::ValueBorrowed>
+    where
+        <Self::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        let hash = self.index.hash(key);
+        self.index
+            .find(&self.entries, hash, key)
+            .next()
+            .map(|position| self.entries[position].value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::map::MapEntry;
+    use crate::context::policy::{KeepAll, KeepFirst};
+    use crate::converter::DebugConverter;
+
+    use super::*;
+
+    type TestEntry = MapEntry<String, str, String, str>;
+    type TestContext<P> = HashMapContext<TestEntry, DebugConverter, P>;
+
+    #[test]
+    fn hash_map_context_operation_succeeds() {
+        let mut context = TestContext::<Replace>::new();
+        context.insert("key1", "1");
+        context.insert_with::<DebugConverter, _, _>("key2", 2);
+        context.insert_with::<DebugConverter, _, _>("key3", "3");
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.get("key2"), Some("2"));
+        assert_eq!(context.get("key3"), Some("\"3\""));
+        assert_eq!(context.get("key4"), None);
+    }
+
+    #[test]
+    fn hash_map_context_insert_succeeds_when_key_is_duplicated() {
+        let context =
+            TestContext::<Replace>::from(vec![("key1", "1"), ("key2", "2"), ("key1", "3")]);
+        assert_eq!(context.get("key1"), Some("3"));
+        let mut iter = context.iter();
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "3")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "2")));
+        assert_eq!(iter.next(), None);
+
+        let context =
+            TestContext::<KeepFirst>::from(vec![("key1", "1"), ("key2", "2"), ("key1", "3")]);
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.iter().count(), 2);
+
+        let context =
+            TestContext::<KeepAll>::from(vec![("key1", "1"), ("key2", "2"), ("key1", "3")]);
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.get_all("key1").collect::<Vec<_>>(), vec!["1", "3"]);
+        let mut iter = context.iter();
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "1")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "2")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "3")));
+        assert_eq!(iter.next(), None);
+    }
+}
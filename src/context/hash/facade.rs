@@ -0,0 +1,41 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::context::hash::HashMapContext;
+use crate::context::map::{AnyMapEntry, StringMapEntry};
+use crate::context::policy::{DuplicatePolicy, Replace};
+use crate::context::{AnyContext, StringContext};
+use crate::converter::{BoxConverter, DebugConverter};
+
+/// A hash map context whose entries are [`StringMapEntry<K, KB>`].
+pub type StringHashMapContext<K, KB, P = Replace> =
+    HashMapContext<StringMapEntry<K, KB>, DebugConverter, P>;
+/// A [`StringHashMapContext<K, KB, P>`] which uses [`String`] as its keys.
+pub type StringKeyStringHashMapContext<P = Replace> = StringHashMapContext<String, str, P>;
+/// A [`StringHashMapContext<K, KB, P>`] which uses `&'static str` as its keys.
+pub type LiteralKeyStringHashMapContext<P = Replace> = StringHashMapContext<&'static str, str, P>;
+
+/// A hash map context whose entries are [`AnyMapEntry<K, KB>`].
+pub type AnyHashMapContext<K, KB, P = Replace> =
+    HashMapContext<AnyMapEntry<K, KB>, BoxConverter, P>;
+/// A [`AnyHashMapContext<K, KB, P>`] which uses [`String`] as its keys.
+pub type StringKeyAnyHashMapContext<P = Replace> = AnyHashMapContext<String, str, P>;
+/// A [`AnyHashMapContext<K, KB, P>`] which uses `&'static str` as its keys.
+pub type LiteralKeyAnyHashMapContext<P = Replace> = AnyHashMapContext<&'static str, str, P>;
+
+impl<K, KB, P> StringContext for StringHashMapContext<K, KB, P>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+    P: DuplicatePolicy,
+{
+}
+
+impl<K, KB, P> AnyContext for AnyHashMapContext<K, KB, P>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+    P: DuplicatePolicy,
+{
+}
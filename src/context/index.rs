@@ -0,0 +1,57 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+use crate::context::Entry;
+
+/// A hash index over the keys of entries stored in a slice, which maps the
+/// hash of each key to the positions of entries with that key.
+#[derive(Debug, Default)]
+pub struct KeyIndex {
+    hasher: RandomState,
+    buckets: HashMap<u64, Vec<usize>>,
+}
+
+impl KeyIndex {
+    pub fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    /// Returns the positions of entries whose key is equal to `key` in
+    /// ascending order.
+    pub fn find<'a, E, Q>(
+        &'a self,
+        entries: &'a [E],
+        hash: u64,
+        key: &'a Q,
+    ) -> impl Iterator<Item = usize> + 'a
+    where
+        E: Entry,
+        E::KeyBorrowed: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.buckets
+            .get(&hash)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |&position| entries[position].key().borrow() == key)
+    }
+
+    pub fn insert(&mut self, hash: u64, position: usize) {
+        let bucket = self.buckets.entry(hash).or_default();
+        let index = bucket.partition_point(|&other| other < position);
+        bucket.insert(index, position);
+    }
+
+    /// Moves all positions starting from `position` one step backwards, which
+    /// is required before an entry is inserted at `position`.
+    pub fn shift(&mut self, position: usize) {
+        self.buckets
+            .values_mut()
+            .flatten()
+            .filter(|other| **other >= position)
+            .for_each(|other| *other += 1);
+    }
+}
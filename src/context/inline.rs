@@ -0,0 +1,213 @@
+mod facade;
+
+pub use facade::*;
+
+use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::iter::{Chain, Flatten};
+use std::marker::PhantomData;
+use std::slice::Iter as SliceIter;
+
+use crate::context::iter::CommonIter;
+use crate::context::{AbstractContext, Context, Entry};
+use crate::converter::Converter;
+
+/// The underlying iterator over the entries of an [`InlineMapContext`].
+pub type InlineIterInner<'a, E> = Chain<Flatten<SliceIter<'a, Option<E>>>, SliceIter<'a, E>>;
+/// The iterator of [`InlineMapContext`].
+pub type InlineMapIter<'a, E> = CommonIter<'a, E, InlineIterInner<'a, E>>;
+
+/// A map-like context which stores up to `N` entries inline.
+///
+/// [`InlineMapContext`] behaves the same as [`MapContext`], but doesn't
+/// allocate until more than `N` entries are inserted, in which case the extra
+/// ones spill into a [`Vec`]. Combined with entries whose keys and values
+/// are [`Cow<'static, str>`], building an error carrying a few literal
+/// context entries costs no allocation for the context at all.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::context::{Context, LiteralKeyCowStringInlineMapContext};
+/// let mut context = LiteralKeyCowStringInlineMapContext::<2>::new();
+/// context.insert("key1", "1");
+/// context.insert("key2", "2");
+/// assert!(!context.is_spilled());
+/// context.insert("key3", "3");
+/// assert!(context.is_spilled());
+/// assert_eq!(context.get("key3"), Some("3"));
+/// ```
+///
+/// [`MapContext`]: crate::context::map::MapContext
+/// [`Cow<'static, str>`]: std::borrow::Cow
+#[derive(Debug, PartialEq, Eq)]
+pub struct InlineMapContext<E: Entry, C: Converter, const N: usize> {
+    inline: [Option<E>; N],
+    len: usize,
+    spilled: Vec<E>,
+    _phantom: PhantomData<C>,
+}
+
+impl<E: Entry, C: Converter, const N: usize> InlineMapContext<E, C, N> {
+    /// Creates a new [`InlineMapContext`].
+    pub fn new() -> Self {
+        Self {
+            inline: std::array::from_fn(|_| None),
+            len: 0,
+            spilled: Vec::new(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the context holds more than `N` entries, i.e. some
+    /// of them have been moved to the heap.
+    pub fn is_spilled(&self) -> bool {
+        !self.spilled.is_empty()
+    }
+
+    fn push(&mut self, entry: E) {
+        if self.len < N {
+            self.inline[self.len] = Some(entry);
+            self.len += 1;
+        } else {
+            self.spilled.push(entry);
+        }
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize> From<Vec<E>> for InlineMapContext<E, C, N> {
+    fn from(entries: Vec<E>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize, Q, R> From<Vec<(Q, R)>> for InlineMapContext<E, C, N>
+where
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from(entries: Vec<(Q, R)>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize> FromIterator<E> for InlineMapContext<E, C, N> {
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut context = Self::new();
+        iter.into_iter().for_each(|entry| context.push(entry));
+        context
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize, Q, R> FromIterator<(Q, R)>
+    for InlineMapContext<E, C, N>
+where
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from_iter<T: IntoIterator<Item = (Q, R)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(|(key, value)| E::new(key.into(), value.into()))
+            .collect()
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize> Default for InlineMapContext<E, C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize> AbstractContext for InlineMapContext<E, C, N> {
+    type Key = E::Key;
+
+    type Value = E::Value;
+
+    type Entry = E;
+
+    type Iter<'a>
+        = InlineMapIter<'a, E>
+    where
+        E: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.inline
+            .iter()
+            .flatten()
+            .chain(self.spilled.iter())
+            .into()
+    }
+}
+
+impl<E: Entry, C: Converter, const N: usize> Context for InlineMapContext<E, C, N> {
+    type Converter = C;
+
+    fn insert<Q, R>(&mut self, key: Q, value: R)
+    where
+        Q: Into<Self::Key>,
+        R: Into<Self::Value>,
+    {
+        self.push(Self::Entry::new(key, value));
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&<Self::Entry as Entry>::ValueBorrowed>
+    where
+        <Self::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        self.iter()
+            .find(|entry| entry.key().borrow() == key)
+            .map(Entry::value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::map::MapEntry;
+    use crate::context::Iter;
+    use crate::converter::DebugConverter;
+
+    use super::*;
+
+    type TestEntry = MapEntry<String, str, String, str>;
+    type TestContext<const N: usize> = InlineMapContext<TestEntry, DebugConverter, N>;
+
+    #[test]
+    fn inline_map_context_operation_succeeds() {
+        let mut context = TestContext::<2>::new();
+        context.insert("key1", "1");
+        context.insert_with::<DebugConverter, _, _>("key2", 2);
+        assert!(!context.is_spilled());
+        context.insert_with::<DebugConverter, _, _>("key3", "3");
+        assert!(context.is_spilled());
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.get("key2"), Some("2"));
+        assert_eq!(context.get("key3"), Some("\"3\""));
+        assert_eq!(context.get("key4"), None);
+    }
+
+    #[test]
+    fn inline_map_context_iter_succeeds_when_spilled() {
+        let context = TestContext::<1>::from(vec![("key1", "1"), ("key2", "2")]);
+        let mut iter = context.iter();
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "1")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "2")));
+        assert_eq!(iter.next(), None);
+
+        let other = TestContext::<1>::from(vec![("key3", "3")]);
+        let mut iter = context.iter().compose(other.iter());
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "1")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "2")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key3", "3")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn inline_map_context_iter_succeeds_when_empty() {
+        let context = TestContext::<0>::new();
+        assert_eq!(context.iter().next(), None);
+        let context = TestContext::<0>::from(vec![("key1", "1")]);
+        assert_eq!(context.get("key1"), Some("1"));
+    }
+}
@@ -0,0 +1,64 @@
+use std::borrow::{Borrow, Cow};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::context::inline::InlineMapContext;
+use crate::context::map::{AnyMapEntry, CowStringMapEntry, StringMapEntry};
+use crate::context::{AnyContext, StringContext};
+use crate::converter::{BoxConverter, DebugConverter, IntoConverter};
+
+/// An inline map context whose entries are [`StringMapEntry<K, KB>`].
+pub type StringInlineMapContext<K, KB, const N: usize> =
+    InlineMapContext<StringMapEntry<K, KB>, DebugConverter, N>;
+/// A [`StringInlineMapContext<K, KB, N>`] which uses [`String`] as its keys.
+pub type StringKeyStringInlineMapContext<const N: usize> = StringInlineMapContext<String, str, N>;
+/// A [`StringInlineMapContext<K, KB, N>`] which uses `&'static str` as its
+/// keys.
+pub type LiteralKeyStringInlineMapContext<const N: usize> =
+    StringInlineMapContext<&'static str, str, N>;
+
+/// An inline map context whose entries are [`CowStringMapEntry<K, KB>`].
+///
+/// Like [`CowStringMapContext<K, KB>`], values are converted with
+/// [`IntoConverter`], so string literals are stored without allocating.
+///
+/// [`CowStringMapContext<K, KB>`]: crate::context::map::CowStringMapContext
+pub type CowStringInlineMapContext<K, KB, const N: usize> =
+    InlineMapContext<CowStringMapEntry<K, KB>, IntoConverter, N>;
+/// A [`CowStringInlineMapContext<K, KB, N>`] which uses
+/// [`Cow<'static, str>`] as its keys.
+pub type CowKeyCowStringInlineMapContext<const N: usize> =
+    CowStringInlineMapContext<Cow<'static, str>, str, N>;
+/// A [`CowStringInlineMapContext<K, KB, N>`] which uses `&'static str` as its
+/// keys.
+pub type LiteralKeyCowStringInlineMapContext<const N: usize> =
+    CowStringInlineMapContext<&'static str, str, N>;
+
+/// An inline map context whose entries are [`AnyMapEntry<K, KB>`].
+pub type AnyInlineMapContext<K, KB, const N: usize> =
+    InlineMapContext<AnyMapEntry<K, KB>, BoxConverter, N>;
+/// A [`AnyInlineMapContext<K, KB, N>`] which uses [`String`] as its keys.
+pub type StringKeyAnyInlineMapContext<const N: usize> = AnyInlineMapContext<String, str, N>;
+/// A [`AnyInlineMapContext<K, KB, N>`] which uses `&'static str` as its keys.
+pub type LiteralKeyAnyInlineMapContext<const N: usize> = AnyInlineMapContext<&'static str, str, N>;
+
+impl<K, KB, const N: usize> StringContext for StringInlineMapContext<K, KB, N>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
+
+impl<K, KB, const N: usize> StringContext for CowStringInlineMapContext<K, KB, N>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
+
+impl<K, KB, const N: usize> AnyContext for AnyInlineMapContext<K, KB, N>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
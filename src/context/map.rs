@@ -3,13 +3,13 @@ mod facade;
 pub use facade::*;
 
 use std::borrow::Borrow;
-use std::fmt::{Debug, Display};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::slice::Iter as SliceIter;
 
 use crate::context::iter::CommonIter;
-use crate::context::{AbstractContext, Context, Entry};
+use crate::context::{fmt_value, structured_value, AbstractContext, Context, Entry, Value};
 use crate::converter::Converter;
 
 /// The iterator of [`MapContext`].
@@ -175,6 +175,15 @@ where
     fn value(&self) -> &Self::ValueBorrowed {
         self.value.borrow()
     }
+
+    fn fmt_entry(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} = ", self.key())?;
+        fmt_value::<V, VB>(&self.value, f)
+    }
+
+    fn structured_value(&self) -> Value {
+        structured_value::<V, VB>(&self.value)
+    }
 }
 
 #[cfg(test)]
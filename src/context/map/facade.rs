@@ -1,11 +1,14 @@
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
 
 use crate::context::any::DynAnyValue;
 use crate::context::map::{MapContext, MapEntry, MapIter};
-use crate::context::{AbstractContext, AnyContext, Entry, StringContext};
-use crate::converter::{BoxConverter, DebugConverter};
+use crate::context::value::Value;
+use crate::context::{AbstractContext, AnyContext, Entry, StringContext, ValueContext};
+use crate::converter::{
+    BoxConverter, DebugConverter, DisplayConverter, IntoConverter, ValueConverter,
+};
 
 /// A map entry whose value is a [`String`].
 pub type StringMapEntry<K, KB> = MapEntry<K, KB, String, str>;
@@ -28,6 +31,61 @@ pub type LiteralKeyStringMapEntry = <LiteralKeyStringMapContext as AbstractConte
 /// The iterator of [`LiteralKeyStringMapContext`].
 pub type LiteralKeyStringMapIter<'a> = <LiteralKeyStringMapContext as AbstractContext>::Iter<'a>;
 
+/// A context whose entries are [`StringMapEntry<K, KB>`] and whose values are
+/// formatted with [`Display`] rather than [`Debug`].
+pub type DisplayStringMapContext<K, KB> = MapContext<StringMapEntry<K, KB>, DisplayConverter>;
+/// The iterator of [`DisplayStringMapContext<K, KB>`].
+pub type DisplayStringMapIter<'a, K, KB> = MapIter<'a, StringMapEntry<K, KB>>;
+
+/// A [`DisplayStringMapContext<K, KB>`] which uses [`String`] as its keys.
+pub type StringKeyDisplayStringMapContext = DisplayStringMapContext<String, str>;
+/// The iterator of [`StringKeyDisplayStringMapContext`].
+pub type StringKeyDisplayStringMapIter<'a> =
+    <StringKeyDisplayStringMapContext as AbstractContext>::Iter<'a>;
+
+/// A [`DisplayStringMapContext<K, KB>`] which uses `&'static str` as its keys.
+pub type LiteralKeyDisplayStringMapContext = DisplayStringMapContext<&'static str, str>;
+/// The iterator of [`LiteralKeyDisplayStringMapContext`].
+pub type LiteralKeyDisplayStringMapIter<'a> =
+    <LiteralKeyDisplayStringMapContext as AbstractContext>::Iter<'a>;
+
+/// A [`StringMapContext<K, KB>`] which uses [`Cow<'static, str>`] as its keys.
+pub type CowKeyStringMapContext = StringMapContext<Cow<'static, str>, str>;
+/// A [`StringMapEntry<K, KB>`] which uses [`Cow<'static, str>`] as its keys.
+pub type CowKeyStringMapEntry = <CowKeyStringMapContext as AbstractContext>::Entry;
+/// The iterator of [`CowKeyStringMapContext`].
+pub type CowKeyStringMapIter<'a> = <CowKeyStringMapContext as AbstractContext>::Iter<'a>;
+
+/// A map entry whose value is a [`Cow<'static, str>`], which saves an
+/// allocation when the value is a string literal.
+pub type CowStringMapEntry<K, KB> = MapEntry<K, KB, Cow<'static, str>, str>;
+/// A context whose entries are [`CowStringMapEntry<K, KB>`].
+///
+/// Values are converted with [`IntoConverter`], so a string literal is stored
+/// as it is without being quoted or copied. Other values can be inserted with
+/// [`Context::insert_with()`] and a converter like [`DebugConverter`].
+///
+/// [`Context::insert_with()`]: crate::context::Context::insert_with
+pub type CowStringMapContext<K, KB> = MapContext<CowStringMapEntry<K, KB>, IntoConverter>;
+/// The iterator of [`CowStringMapContext<K, KB>`].
+pub type CowStringMapIter<'a, K, KB> = MapIter<'a, CowStringMapEntry<K, KB>>;
+
+/// A [`CowStringMapContext<K, KB>`] which uses [`Cow<'static, str>`] as its
+/// keys.
+pub type CowKeyCowStringMapContext = CowStringMapContext<Cow<'static, str>, str>;
+/// A [`CowStringMapEntry<K, KB>`] which uses [`Cow<'static, str>`] as its keys.
+pub type CowKeyCowStringMapEntry = <CowKeyCowStringMapContext as AbstractContext>::Entry;
+/// The iterator of [`CowKeyCowStringMapContext`].
+pub type CowKeyCowStringMapIter<'a> = <CowKeyCowStringMapContext as AbstractContext>::Iter<'a>;
+
+/// A [`CowStringMapContext<K, KB>`] which uses `&'static str` as its keys.
+pub type LiteralKeyCowStringMapContext = CowStringMapContext<&'static str, str>;
+/// A [`CowStringMapEntry<K, KB>`] which uses `&'static str` as its keys.
+pub type LiteralKeyCowStringMapEntry = <LiteralKeyCowStringMapContext as AbstractContext>::Entry;
+/// The iterator of [`LiteralKeyCowStringMapContext`].
+pub type LiteralKeyCowStringMapIter<'a> =
+    <LiteralKeyCowStringMapContext as AbstractContext>::Iter<'a>;
+
 /// A map entry whose value is a [`Box<DynAnyValue>`].
 pub type AnyMapEntry<K, KB> = MapEntry<K, KB, Box<DynAnyValue>, DynAnyValue>;
 /// A context whose entries are [`AnyMapEntry<K, KB>`].
@@ -49,13 +107,42 @@ pub type LiteralKeyAnyMapEntry = <LiteralKeyAnyMapContext as AbstractContext>::E
 /// The iterator of [`LiteralKeyAnyMapContext`].
 pub type LiteralKeyAnyMapIter<'a> = <LiteralKeyAnyMapContext as AbstractContext>::Iter<'a>;
 
-impl<K, KB> Display for StringMapEntry<K, KB>
+/// A [`AnyMapContext<K, KB>`] which uses [`Cow<'static, str>`] as its keys.
+pub type CowKeyAnyMapContext = AnyMapContext<Cow<'static, str>, str>;
+/// A [`AnyMapEntry<K, KB>`] which uses [`Cow<'static, str>`] as its keys.
+pub type CowKeyAnyMapEntry = <CowKeyAnyMapContext as AbstractContext>::Entry;
+/// The iterator of [`CowKeyAnyMapContext`].
+pub type CowKeyAnyMapIter<'a> = <CowKeyAnyMapContext as AbstractContext>::Iter<'a>;
+
+/// A map entry whose value is a structured [`Value`].
+pub type ValueMapEntry<K, KB> = MapEntry<K, KB, Value, Value>;
+/// A context whose entries are [`ValueMapEntry<K, KB>`].
+pub type ValueMapContext<K, KB> = MapContext<ValueMapEntry<K, KB>, ValueConverter>;
+/// The iterator of [`ValueMapContext<K, KB>`].
+pub type ValueMapIter<'a, K, KB> = MapIter<'a, ValueMapEntry<K, KB>>;
+
+/// A [`ValueMapContext<K, KB>`] which uses [`String`] as its keys.
+pub type StringKeyValueMapContext = ValueMapContext<String, str>;
+/// A [`ValueMapEntry<K, KB>`] which uses [`String`] as its keys.
+pub type StringKeyValueMapEntry = <StringKeyValueMapContext as AbstractContext>::Entry;
+/// The iterator of [`StringKeyValueMapContext`].
+pub type StringKeyValueMapIter<'a> = <StringKeyValueMapContext as AbstractContext>::Iter<'a>;
+
+/// A [`ValueMapContext<K, KB>`] which uses `&'static str` as its keys.
+pub type LiteralKeyValueMapContext = ValueMapContext<&'static str, str>;
+/// A [`ValueMapEntry<K, KB>`] which uses `&'static str` as its keys.
+pub type LiteralKeyValueMapEntry = <LiteralKeyValueMapContext as AbstractContext>::Entry;
+/// The iterator of [`LiteralKeyValueMapContext`].
+pub type LiteralKeyValueMapIter<'a> = <LiteralKeyValueMapContext as AbstractContext>::Iter<'a>;
+
+impl<K, KB, V> Display for MapEntry<K, KB, V, str>
 where
     K: Borrow<KB> + Debug + Send + Sync + 'static,
     KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+    V: Borrow<str> + Debug + Send + Sync + 'static,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{} = {}", self.key(), self.value())
+        self.fmt_entry(f)
     }
 }
 
@@ -66,13 +153,27 @@ where
 {
 }
 
+impl<K, KB> StringContext for DisplayStringMapContext<K, KB>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
+
+impl<K, KB> StringContext for CowStringMapContext<K, KB>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
+
 impl<K, KB> Display for AnyMapEntry<K, KB>
 where
     K: Borrow<KB> + Debug + Send + Sync + 'static,
     KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{} = {:?}", self.key(), self.value())
+        self.fmt_entry(f)
     }
 }
 
@@ -83,6 +184,23 @@ where
 {
 }
 
+impl<K, KB> Display for ValueMapEntry<K, KB>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_entry(f)
+    }
+}
+
+impl<K, KB> ValueContext for ValueMapContext<K, KB>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + ?Sized + Send + Sync,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::context::Context;
@@ -101,4 +219,101 @@ mod tests {
         assert_eq!(ctx.value_as::<(), _>("i32"), None);
         assert_eq!(ctx.value_as::<(), _>("string"), None);
     }
+
+    #[test]
+    fn display_string_map_context_operation() {
+        let mut ctx = LiteralKeyDisplayStringMapContext::new();
+        ctx.insert_with::<DisplayConverter, _, _>("i32", 1i32);
+        ctx.insert_with::<DisplayConverter, _, _>("path", std::path::Path::new("/tmp").display());
+        ctx.insert_with::<DisplayConverter, _, _>("string", "a \"quoted\" text");
+        assert_eq!(ctx.get("i32"), Some("1"));
+        assert_eq!(ctx.get("path"), Some("/tmp"));
+        assert_eq!(ctx.get("string"), Some("a \"quoted\" text"));
+
+        let entries = ctx.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            ["i32 = 1", "path = /tmp", "string = a \"quoted\" text"]
+        );
+    }
+
+    #[test]
+    fn value_map_context_operation() {
+        let mut ctx = LiteralKeyValueMapContext::new();
+        ctx.insert_with::<ValueConverter, _, _>("i32", 1i32);
+        ctx.insert_with::<ValueConverter, _, _>("list", vec!["a", "b"]);
+        ctx.insert("none", Value::Null);
+        assert_eq!(ctx.get("i32"), Some(&Value::I64(1)));
+        assert_eq!(ctx.get("none"), Some(&Value::Null));
+
+        let entries = ctx.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(entries, ["i32 = 1", r#"list = ["a","b"]"#, "none = null"]);
+        assert_eq!(ctx.to_json(), r#"{"i32":1,"list":["a","b"],"none":null}"#);
+    }
+
+    #[test]
+    fn cow_string_map_context_operation() {
+        let mut ctx = CowKeyCowStringMapContext::new();
+        ctx.insert("literal", "value");
+        ctx.insert(String::from("owned"), String::from("value"));
+        ctx.insert_with::<DebugConverter, _, _>("i32", 1i32);
+        assert_eq!(ctx.get("literal"), Some("value"));
+        assert_eq!(ctx.get("owned"), Some("value"));
+        assert_eq!(ctx.get("i32"), Some("1"));
+
+        let mut iter = ctx.iter();
+        let entry = iter.next().unwrap();
+        assert!(matches!(entry.key, Cow::Borrowed("literal")));
+        assert!(matches!(entry.value, Cow::Borrowed("value")));
+        assert_eq!(entry.to_string(), "literal = value");
+    }
+
+    #[test]
+    fn cow_string_map_context_borrows_literals_from_builder() {
+        use crate::context::LiteralKeyCowStringInlineMapContext;
+        use crate::core::{AnyError, ContextDepth};
+        use crate::kind::DefaultErrorKind;
+
+        let err = AnyError::<LiteralKeyCowStringMapContext, DefaultErrorKind>::builder()
+            .message("error")
+            .context("key", "value")
+            .build();
+        assert_eq!(err.get("key"), Some("value"));
+        let entry = err.context(ContextDepth::All).next().unwrap();
+        assert!(matches!(entry.value, Cow::Borrowed("value")));
+
+        let err = AnyError::<LiteralKeyCowStringInlineMapContext<2>, DefaultErrorKind>::builder()
+            .message("error")
+            .context("key", "value")
+            .build();
+        assert_eq!(err.get("key"), Some("value"));
+        let entry = err.context(ContextDepth::All).next().unwrap();
+        assert!(matches!(entry.value, Cow::Borrowed("value")));
+    }
+
+    #[test]
+    fn map_entry_fmt_entry_succeeds() {
+        struct FmtEntry<'a, E: Entry>(&'a E);
+
+        impl<E: Entry> Display for FmtEntry<'_, E> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                self.0.fmt_entry(f)
+            }
+        }
+
+        let entry = LiteralKeyStringMapEntry::new("key", "\"value\"");
+        assert_eq!(FmtEntry(&entry).to_string(), "key = \"value\"");
+        assert_eq!(FmtEntry(&entry).to_string(), entry.to_string());
+
+        let entry = LiteralKeyAnyMapEntry::new("key", Box::new(42) as Box<DynAnyValue>);
+        assert_eq!(FmtEntry(&entry).to_string(), "key = 42");
+        assert_eq!(FmtEntry(&entry).to_string(), entry.to_string());
+
+        let entry = LiteralKeyValueMapEntry::new("key", vec!["a"]);
+        assert_eq!(FmtEntry(&entry).to_string(), r#"key = ["a"]"#);
+        assert_eq!(FmtEntry(&entry).to_string(), entry.to_string());
+
+        let entry = MapEntry::<&'static str, str, i32, i32>::new("key", 42);
+        assert_eq!(FmtEntry(&entry).to_string(), "key = 42");
+    }
 }
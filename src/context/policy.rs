@@ -0,0 +1,252 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::slice::Iter as SliceIter;
+
+use crate::context::{Entry, Value};
+
+/// The trait used to select how a context handles an incoming entry whose key
+/// already exists.
+///
+/// Like a [`Converter`], a [`DuplicatePolicy`] is a marker type which is
+/// chosen as a type parameter of the context, such as [`HashMapContext`] and
+/// [`SortedMapContext`].
+///
+/// [`Converter`]: crate::converter::Converter
+/// [`HashMapContext`]: crate::context::hash::HashMapContext
+/// [`SortedMapContext`]: crate::context::sorted::SortedMapContext
+pub trait DuplicatePolicy: Debug + Send + Sync + 'static {
+    /// Resolves the conflict between the `existing` entry and the `incoming`
+    /// one which has the same key. Returns the `incoming` entry back if it
+    /// should also be stored in the context.
+    fn resolve<E: Entry>(existing: &mut E, incoming: E) -> Option<E>;
+}
+
+/// A policy which replaces the existing entry's value with the new one.
+#[derive(Debug)]
+pub struct Replace;
+
+impl DuplicatePolicy for Replace {
+    fn resolve<E: Entry>(existing: &mut E, incoming: E) -> Option<E> {
+        *existing = incoming;
+        None
+    }
+}
+
+/// A policy which keeps the existing entry and discards the new one.
+#[derive(Debug)]
+pub struct KeepFirst;
+
+impl DuplicatePolicy for KeepFirst {
+    fn resolve<E: Entry>(_existing: &mut E, _incoming: E) -> Option<E> {
+        None
+    }
+}
+
+/// A policy which keeps all entries even if they share the same key.
+#[derive(Debug)]
+pub struct KeepAll;
+
+impl DuplicatePolicy for KeepAll {
+    fn resolve<E: Entry>(_existing: &mut E, incoming: E) -> Option<E> {
+        Some(incoming)
+    }
+}
+
+/// The policy used to merge entries sharing the same key, which may come from
+/// different layers of an error or be inserted into the same layer multiple
+/// times.
+///
+/// Entries are merged in the order they're iterated, which is from the
+/// outermost layer to the innermost one, and in insertion order within a
+/// layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergePolicy {
+    /// Keeps all entries, even if some of them share the same key.
+    #[default]
+    All,
+    /// Keeps the first entry of each key, i.e. the one from the outermost
+    /// layer, which is consistent with [`AnyError::get_deep()`].
+    ///
+    /// [`AnyError::get_deep()`]: crate::AnyError::get_deep
+    OutermostWins,
+    /// Keeps the last entry of each key, i.e. the one from the innermost
+    /// layer.
+    InnermostWins,
+}
+
+impl MergePolicy {
+    /// Merges the `entries` according to the policy. The surviving entries
+    /// keep their original relative order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::context::{AbstractContext, Context, LiteralKeyStringMapContext, MergePolicy};
+    /// let context = LiteralKeyStringMapContext::from(vec![("a", "1"), ("b", "2"), ("a", "3")]);
+    /// let merged = MergePolicy::InnermostWins.merge(context.iter());
+    /// assert_eq!(merged.get("a"), Some("3"));
+    /// assert_eq!(merged.len(), 2);
+    /// ```
+    pub fn merge<'a, E, I>(self, entries: I) -> MergedContext<'a, E>
+    where
+        E: Entry,
+        I: IntoIterator<Item = &'a E>,
+    {
+        let entries = entries.into_iter();
+        let entries = match self {
+            Self::All => entries.collect(),
+            Self::OutermostWins => {
+                let mut keys = HashSet::new();
+                entries.filter(|entry| keys.insert(entry.key())).collect()
+            }
+            Self::InnermostWins => {
+                let mut keys = HashSet::new();
+                let mut entries = entries.collect::<Vec<_>>();
+                entries.reverse();
+                entries.retain(|entry| keys.insert(entry.key()));
+                entries.reverse();
+                entries
+            }
+        };
+        MergedContext { entries }
+    }
+}
+
+/// The effective view of entries merged by a [`MergePolicy`].
+#[derive(Debug, Clone)]
+pub struct MergedContext<'a, E: Entry> {
+    entries: Vec<&'a E>,
+}
+
+impl<'a, E: Entry> MergedContext<'a, E> {
+    /// Returns the value corresponding to the given key. If the entries are
+    /// merged with [`MergePolicy::All`], the first matching one is returned.
+    pub fn get<Q>(&self, key: &Q) -> Option<&'a E::ValueBorrowed>
+    where
+        E::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        self.entries
+            .iter()
+            .find(|entry| entry.key().borrow() == key)
+            .map(|entry| entry.value())
+    }
+
+    /// Returns `true` if `entry` is one of the merged entries. Entries are
+    /// compared by identity rather than by value.
+    pub fn contains(&self, entry: &E) -> bool {
+        self.entries.iter().any(|other| std::ptr::eq(*other, entry))
+    }
+
+    /// Returns the number of merged entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there's no entry.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the merged entries.
+    pub fn iter(&self) -> impl Iterator<Item = &'a E> + '_ {
+        self.entries.iter().copied()
+    }
+}
+
+impl<E> MergedContext<'_, E>
+where
+    E: Entry<ValueBorrowed = Value>,
+{
+    /// Collects the merged entries into a [`Value::Map`] whose keys are the
+    /// [`Display`] representations of the entries' keys. If multiple entries
+    /// share the same key, the first one is kept.
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub fn to_value(&self) -> Value {
+        let mut map = BTreeMap::new();
+        for entry in self.iter() {
+            map.entry(entry.key().to_string())
+                .or_insert_with(|| entry.value().clone());
+        }
+        Value::Map(map)
+    }
+}
+
+impl<'a, E: Entry> IntoIterator for MergedContext<'a, E> {
+    type Item = &'a E;
+
+    type IntoIter = std::vec::IntoIter<&'a E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, 'b, E: Entry> IntoIterator for &'b MergedContext<'a, E> {
+    type Item = &'a E;
+
+    type IntoIter = std::iter::Copied<SliceIter<'b, &'a E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::{AbstractContext, Context, LiteralKeyStringMapContext};
+    use crate::context::{LiteralKeyValueMapContext, ValueContext};
+
+    use super::*;
+
+    #[test]
+    fn merge_policy_merge_succeeds() {
+        let context = LiteralKeyStringMapContext::from(vec![
+            ("a", "1"),
+            ("b", "2"),
+            ("a", "3"),
+            ("c", "4"),
+            ("b", "5"),
+        ]);
+        let keys_and_values = |policy: MergePolicy| {
+            policy
+                .merge(context.iter())
+                .into_iter()
+                .map(|entry| (entry.key(), entry.value()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(keys_and_values(MergePolicy::All).len(), 5);
+        assert_eq!(
+            keys_and_values(MergePolicy::OutermostWins),
+            [("a", "1"), ("b", "2"), ("c", "4")]
+        );
+        assert_eq!(
+            keys_and_values(MergePolicy::InnermostWins),
+            [("a", "3"), ("c", "4"), ("b", "5")]
+        );
+
+        let merged = MergePolicy::OutermostWins.merge(context.iter());
+        assert_eq!(merged.get("b"), Some("2"));
+        assert_eq!(merged.get("d"), None);
+        assert!(merged.contains(context.iter().next().unwrap()));
+        assert!(!merged.contains(context.iter().nth(2).unwrap()));
+    }
+
+    #[test]
+    fn merged_context_to_value_succeeds() {
+        let mut context = LiteralKeyValueMapContext::new();
+        context.insert("a", 1);
+        context.insert("b", 2);
+        context.insert("a", 3);
+
+        let merged = MergePolicy::InnermostWins.merge(context.iter());
+        assert_eq!(merged.to_value().to_json(), r#"{"a":3,"b":2}"#);
+        let merged = MergePolicy::All.merge(context.iter());
+        assert_eq!(merged.to_value(), context.to_value());
+        assert_eq!(context.to_json(), r#"{"a":1,"b":2}"#);
+    }
+}
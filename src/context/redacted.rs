@@ -0,0 +1,150 @@
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+use crate::context::Value;
+
+/// A wrapper which hides the wrapped value whenever it's formatted.
+///
+/// Sensitive context values such as tokens, emails and passwords can be
+/// wrapped in a [`Redacted`] before being attached to an error. Both the
+/// [`Debug`] and [`Display`] representations of a [`Redacted`] are
+/// `<redacted>`, so the entry keeps its key but the value never shows up in
+/// reports. A [`Redacted`] is also converted into the string `"<redacted>"`
+/// as a [`Value`], so it's masked in serialized output, such as the JSON
+/// produced by a [`ValueContext`], as well. For contexts holding the values
+/// themselves, such as an [`AnyContext`], the raw value is still reachable
+/// in-process through [`AnyContext::value_as()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::{LiteralKeyAnyMapContext, Redacted};
+/// # use anyerr::kind::DefaultErrorKind;
+/// # use anyerr::report::Report;
+/// type AnyError = AnyErrorTemplate<LiteralKeyAnyMapContext, DefaultErrorKind>;
+///
+/// let err = AnyError::builder()
+///     .message("could not authenticate the user")
+///     .context("token", Redacted::new("secret"))
+///     .build();
+/// assert_eq!(err.value_as::<&str, _>("token"), Some(&"secret"));
+///
+/// let report = Report::wrap(err).pretty(false);
+/// assert_eq!(
+///     report.to_string(),
+///     "(Unknown) could not authenticate the user [token = <redacted>]"
+/// );
+/// ```
+///
+/// [`AnyContext`]: crate::context::AnyContext
+/// [`ValueContext`]: crate::context::ValueContext
+/// [`AnyContext::value_as()`]: crate::context::AnyContext::value_as
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+    const PLACEHOLDER: &'static str = "<redacted>";
+
+    /// Wraps the `value` in a [`Redacted`].
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the [`Redacted`] and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Redacted<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Debug for Redacted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", Self::PLACEHOLDER)
+    }
+}
+
+impl<T> Display for Redacted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", Self::PLACEHOLDER)
+    }
+}
+
+impl<T> From<Redacted<T>> for Value {
+    fn from(_: Redacted<T>) -> Self {
+        Value::String(Redacted::<T>::PLACEHOLDER.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::{
+        AnyContext, Context, LiteralKeyAnyMapContext, LiteralKeyStringMapContext,
+        LiteralKeyValueMapContext, ValueContext,
+    };
+    use crate::converter::{BoxConverter, DebugConverter, ValueConverter};
+    use crate::core::ContextDepth;
+    use crate::kind::DefaultErrorKind;
+    use crate::AnyError;
+
+    use super::*;
+
+    #[test]
+    fn redacted_format_succeeds() {
+        let value = Redacted::new("secret");
+        assert_eq!(format!("{value:?}"), "<redacted>");
+        assert_eq!(format!("{value}"), "<redacted>");
+        assert_eq!(*value.expose(), "secret");
+        assert_eq!(value.into_inner(), "secret");
+    }
+
+    #[test]
+    fn redacted_string_context_hides_value() {
+        let mut context = LiteralKeyStringMapContext::new();
+        context.insert_with::<DebugConverter, _, _>("password", Redacted::new("secret"));
+        assert_eq!(context.get("password"), Some("<redacted>"));
+    }
+
+    #[test]
+    fn redacted_any_context_exposes_value() {
+        let mut context = LiteralKeyAnyMapContext::new();
+        context.insert_with::<BoxConverter, _, _>("password", Redacted::new("secret"));
+        assert_eq!(context.value_as::<&str, _>("password"), Some(&"secret"));
+        assert_eq!(
+            context.value_as::<Redacted<&str>, _>("password"),
+            Some(&Redacted::new("secret"))
+        );
+        assert_eq!(context.value_as::<String, _>("password"), None);
+        assert_eq!(
+            format!("{:?}", context.get("password").unwrap()),
+            "<redacted>"
+        );
+    }
+
+    #[test]
+    fn redacted_value_context_hides_value_in_json() {
+        let mut context = LiteralKeyValueMapContext::new();
+        context.insert_with::<ValueConverter, _, _>("password", Redacted::new("secret"));
+        context.insert_with::<ValueConverter, _, _>("user", "alice");
+        let json = context.to_json();
+        assert_eq!(json, r#"{"password":"<redacted>","user":"alice"}"#);
+        assert!(!json.contains("secret"));
+
+        let err = AnyError::<LiteralKeyValueMapContext, DefaultErrorKind>::builder()
+            .message("could not authenticate the user")
+            .context("token", Redacted::new(String::from("secret")))
+            .build();
+        let json = err.context_value(ContextDepth::All).to_json();
+        assert_eq!(json, r#"{"token":"<redacted>"}"#);
+        assert!(!json.contains("secret"));
+    }
+}
@@ -7,7 +7,9 @@ use std::marker::PhantomData;
 use std::option::Iter as InnerIter;
 
 use crate::context::iter::CommonIter;
-use crate::context::{AbstractContext, Context, Entry, SingletonContext};
+use crate::context::{
+    fmt_value, structured_value, AbstractContext, Context, Entry, SingletonContext, Value,
+};
 use crate::converter::IntoConverter;
 
 pub use facade::*;
@@ -158,6 +160,14 @@ where
     fn value(&self) -> &Self::ValueBorrowed {
         self.value.borrow()
     }
+
+    fn fmt_entry(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fmt_value::<V, VB>(&self.value, f)
+    }
+
+    fn structured_value(&self) -> Value {
+        structured_value::<V, VB>(&self.value)
+    }
 }
 
 /// The key of [`OptionEntry`].
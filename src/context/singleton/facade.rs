@@ -1,5 +1,7 @@
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
 use crate::context::singleton::{OptionContext, OptionEntry};
-use crate::context::{AbstractContext, DynAnyValue};
+use crate::context::{AbstractContext, DynAnyValue, Entry};
 
 /// A singleton entry whose value is a [`String`].
 pub type StringSingletonEntry = OptionEntry<String, str>;
@@ -21,3 +23,24 @@ pub type FixedSingletonEntry<T> = OptionEntry<T, T>;
 pub type FixedSingletonContext<T> = OptionContext<FixedSingletonEntry<T>>;
 /// The iterator of [`FixedSingletonContext<T>`].
 pub type FixedSingletonIter<'a, T> = <FixedSingletonContext<T> as AbstractContext>::Iter<'a>;
+
+impl Display for StringSingletonEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_entry(f)
+    }
+}
+
+impl Display for AnySingletonEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_entry(f)
+    }
+}
+
+impl<T> Display for FixedSingletonEntry<T>
+where
+    T: Debug + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_entry(f)
+    }
+}
@@ -0,0 +1,295 @@
+mod facade;
+
+pub use facade::*;
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::context::index::KeyIndex;
+use crate::context::map::MapIter;
+use crate::context::policy::{DuplicatePolicy, Replace};
+use crate::context::{AbstractContext, Context, Entry};
+use crate::converter::Converter;
+
+/// A map-like context which keeps its entries sorted by key.
+///
+/// Like [`HashMapContext`], [`SortedMapContext`] looks up entries through a
+/// hash index, so its keys must implement [`Hash`] as well as [`Ord`]. Its
+/// entries are stored in a [`Vec`] sorted in the ascending order of their
+/// keys rather than the order they're inserted, so reports list the context
+/// alphabetically regardless of where each entry was attached. Entries with
+/// the same key are kept in the order they're inserted.
+///
+/// Keeping the entries sorted makes each insertion take `O(n)` time, while a
+/// lookup takes `O(1)` time on average. This suits the small number of
+/// entries an error usually carries, but isn't meant to be a general-purpose
+/// ordered map.
+///
+/// Inserting an entry whose key already exists is handled by the
+/// [`DuplicatePolicy`] `P`, which replaces the existing value by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::context::{Context, KeepFirst, LiteralKeyStringSortedMapContext};
+/// let mut context = LiteralKeyStringSortedMapContext::<KeepFirst>::new();
+/// context.insert("key", "1");
+/// context.insert("key", "2");
+/// assert_eq!(context.get("key"), Some("1"));
+/// ```
+///
+/// [`HashMapContext`]: crate::context::hash::HashMapContext
+pub struct SortedMapContext<E, C, P = Replace>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    entries: Vec<E>,
+    index: KeyIndex,
+    _phantom: PhantomData<(C, P)>,
+}
+
+impl<E, C, P> SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    /// Creates a new [`SortedMapContext`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: KeyIndex::default(),
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns an iterator over all values corresponding to the given key,
+    /// which yields more than one value only if `P` is [`KeepAll`].
+    ///
+    /// [`KeepAll`]: crate::context::policy::KeepAll
+    pub fn get_all<'a, Q>(&'a self, key: &'a Q) -> impl Iterator<Item = &'a E::ValueBorrowed>
+    where
+        E::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        let hash = self.index.hash(key);
+        self.index
+            .find(&self.entries, hash, key)
+            .map(|position| self.entries[position].value())
+    }
+
+    fn insert_entry(&mut self, entry: E) {
+        let hash = self.index.hash(entry.key());
+        let existing = self.index.find(&self.entries, hash, entry.key()).next();
+        let entry = match existing {
+            Some(position) => P::resolve(&mut self.entries[position], entry),
+            None => Some(entry),
+        };
+        if let Some(entry) = entry {
+            let position = self
+                .entries
+                .partition_point(|other| other.key() <= entry.key());
+            self.index.shift(position);
+            self.index.insert(hash, position);
+            self.entries.insert(position, entry);
+        }
+    }
+}
+
+impl<E, C, P> From<Vec<E>> for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn from(entries: Vec<E>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E, C, P, Q, R> From<Vec<(Q, R)>> for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from(entries: Vec<(Q, R)>) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl<E, C, P> FromIterator<E> for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        let mut context = Self::new();
+        iter.into_iter()
+            .for_each(|entry| context.insert_entry(entry));
+        context
+    }
+}
+
+impl<E, C, P, Q, R> FromIterator<(Q, R)> for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+    Q: Into<<Self as AbstractContext>::Key>,
+    R: Into<<Self as AbstractContext>::Value>,
+{
+    fn from_iter<T: IntoIterator<Item = (Q, R)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(|(key, value)| E::new(key.into(), value.into()))
+            .collect()
+    }
+}
+
+impl<E, C, P> Default for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, C, P> Debug for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SortedMapContext")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl<E, C, P> AbstractContext for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    type Key = E::Key;
+
+    type Value = E::Value;
+
+    type Entry = E;
+
+    type Iter<'a>
+        = MapIter<'a, E>
+    where
+        E: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.entries.iter().into()
+    }
+}
+
+impl<E, C, P> Context for SortedMapContext<E, C, P>
+where
+    E: Entry,
+    E::KeyBorrowed: Ord,
+    C: Converter,
+    P: DuplicatePolicy,
+{
+    type Converter = C;
+
+    fn insert<Q, R>(&mut self, key: Q, value: R)
+    where
+        Q: Into<Self::Key>,
+        R: Into<Self::Value>,
+    {
+        self.insert_entry(Self::Entry::new(key, value));
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&<Self::Entry as Entry>::ValueBorrowed>
+    where
+        <Self::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        let hash = self.index.hash(key);
+        self.index
+            .find(&self.entries, hash, key)
+            .next()
+            .map(|position| self.entries[position].value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::map::MapEntry;
+    use crate::context::policy::{KeepAll, KeepFirst};
+    use crate::converter::DebugConverter;
+
+    use super::*;
+
+    type TestEntry = MapEntry<String, str, String, str>;
+    type TestContext<P> = SortedMapContext<TestEntry, DebugConverter, P>;
+
+    #[test]
+    fn sorted_map_context_operation_succeeds() {
+        let mut context = TestContext::<Replace>::new();
+        context.insert("key1", "1");
+        context.insert_with::<DebugConverter, _, _>("key2", 2);
+        context.insert_with::<DebugConverter, _, _>("key3", "3");
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.get("key2"), Some("2"));
+        assert_eq!(context.get("key3"), Some("\"3\""));
+        assert_eq!(context.get("key4"), None);
+    }
+
+    #[test]
+    fn sorted_map_context_insert_succeeds_when_key_is_duplicated() {
+        let context =
+            TestContext::<Replace>::from(vec![("key2", "2"), ("key1", "1"), ("key2", "3")]);
+        assert_eq!(context.get("key2"), Some("3"));
+        let mut iter = context.iter();
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "1")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "3")));
+        assert_eq!(iter.next(), None);
+
+        let context =
+            TestContext::<KeepFirst>::from(vec![("key1", "1"), ("key2", "2"), ("key1", "3")]);
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.iter().count(), 2);
+
+        let context = TestContext::<KeepAll>::from(vec![
+            ("key2", "2"),
+            ("key1", "1"),
+            ("key3", "3"),
+            ("key1", "4"),
+        ]);
+        assert_eq!(context.get("key1"), Some("1"));
+        assert_eq!(context.get_all("key1").collect::<Vec<_>>(), vec!["1", "4"]);
+        assert_eq!(context.get("key3"), Some("3"));
+        let mut iter = context.iter();
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "1")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key1", "4")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key2", "2")));
+        assert_eq!(iter.next(), Some(&TestEntry::new("key3", "3")));
+        assert_eq!(iter.next(), None);
+    }
+}
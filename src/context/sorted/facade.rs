@@ -0,0 +1,42 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::context::map::{AnyMapEntry, StringMapEntry};
+use crate::context::policy::{DuplicatePolicy, Replace};
+use crate::context::sorted::SortedMapContext;
+use crate::context::{AnyContext, StringContext};
+use crate::converter::{BoxConverter, DebugConverter};
+
+/// A sorted map context whose entries are [`StringMapEntry<K, KB>`].
+pub type StringSortedMapContext<K, KB, P = Replace> =
+    SortedMapContext<StringMapEntry<K, KB>, DebugConverter, P>;
+/// A [`StringSortedMapContext<K, KB, P>`] which uses [`String`] as its keys.
+pub type StringKeyStringSortedMapContext<P = Replace> = StringSortedMapContext<String, str, P>;
+/// A [`StringSortedMapContext<K, KB, P>`] which uses `&'static str` as its keys.
+pub type LiteralKeyStringSortedMapContext<P = Replace> =
+    StringSortedMapContext<&'static str, str, P>;
+
+/// A sorted map context whose entries are [`AnyMapEntry<K, KB>`].
+pub type AnySortedMapContext<K, KB, P = Replace> =
+    SortedMapContext<AnyMapEntry<K, KB>, BoxConverter, P>;
+/// A [`AnySortedMapContext<K, KB, P>`] which uses [`String`] as its keys.
+pub type StringKeyAnySortedMapContext<P = Replace> = AnySortedMapContext<String, str, P>;
+/// A [`AnySortedMapContext<K, KB, P>`] which uses `&'static str` as its keys.
+pub type LiteralKeyAnySortedMapContext<P = Replace> = AnySortedMapContext<&'static str, str, P>;
+
+impl<K, KB, P> StringContext for StringSortedMapContext<K, KB, P>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + Ord + ?Sized + Send + Sync,
+    P: DuplicatePolicy,
+{
+}
+
+impl<K, KB, P> AnyContext for AnySortedMapContext<K, KB, P>
+where
+    K: Borrow<KB> + Debug + Send + Sync + 'static,
+    KB: Debug + Display + Eq + Hash + Ord + ?Sized + Send + Sync,
+    P: DuplicatePolicy,
+{
+}
@@ -46,6 +46,12 @@ impl Display for Dummy {
 #[derive(Debug)]
 pub enum DummyEntry {}
 
+impl Display for DummyEntry {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> FmtResult {
+        match *self {}
+    }
+}
+
 impl Entry for DummyEntry {
     type Key = Dummy;
 
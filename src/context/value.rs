@@ -0,0 +1,343 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+/// A structured value which resembles a JSON value.
+///
+/// Unlike a [`String`] produced by the [`DebugConverter`], a [`Value`] keeps
+/// the structure of the original data, so integers stay integers and nested
+/// lists and maps are preserved. Its [`Display`] implementation prints the
+/// value in JSON, which makes contexts holding [`Value`]s easy to serialize.
+///
+/// A [`Value`] is typically made with the [`From`] implementations for
+/// primitive types, strings, [`Option<T>`], [`Vec<T>`] and maps, and is used
+/// by contexts whose converter is the [`ValueConverter`].
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::context::Value;
+/// assert_eq!(Value::from(42).to_string(), "42");
+/// assert_eq!(Value::from("text").to_string(), r#""text""#);
+/// assert_eq!(Value::from(vec![1, 2]).to_string(), "[1,2]");
+/// assert_eq!(Value::from(None::<i32>).to_string(), "null");
+/// ```
+///
+/// [`DebugConverter`]: crate::converter::DebugConverter
+/// [`ValueConverter`]: crate::converter::ValueConverter
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Value {
+    /// The absence of a value.
+    #[default]
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// A signed integer.
+    I64(i64),
+    /// An unsigned integer.
+    U64(u64),
+    /// A floating-point number.
+    F64(f64),
+    /// A string.
+    String(String),
+    /// A list of values.
+    List(Vec<Value>),
+    /// A map from strings to values, whose entries are sorted by key.
+    Map(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Returns `true` if the value is [`Value::Null`].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns the boolean if the value is a [`Value::Bool`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer if the value is an integer representable by an
+    /// [`i64`].
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::I64(value) => Some(*value),
+            Self::U64(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer if the value is an integer representable by an
+    /// [`u64`].
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::I64(value) => u64::try_from(*value).ok(),
+            Self::U64(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as an [`f64`] if the value is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::I64(value) => Some(*value as f64),
+            Self::U64(value) => Some(*value as f64),
+            Self::F64(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the string slice if the value is a [`Value::String`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a [`Value::List`].
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Self::List(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the map if the value is a [`Value::Map`].
+    pub fn as_map(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Self::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Returns the JSON representation of the value, which is the same as its
+    /// [`Display`] representation.
+    pub fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::I64(value) => write!(f, "{value}"),
+            Self::U64(value) => write!(f, "{value}"),
+            // JSON has no representation for NaN and infinities.
+            Self::F64(value) if !value.is_finite() => write!(f, "null"),
+            Self::F64(value) => write!(f, "{value:?}"),
+            Self::String(value) => write_json_string(f, value),
+            Self::List(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Self::Map(map) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in map.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ",")?;
+                    }
+                    write_json_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Writes `value` as a quoted and escaped JSON string.
+fn write_json_string(f: &mut Formatter<'_>, value: &str) -> FmtResult {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{08}' => f.write_str("\\b")?,
+            '\u{0c}' => f.write_str("\\f")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident: $target:ty => $($source:ty),*) => {
+        $(
+            impl From<$source> for Value {
+                fn from(value: $source) -> Self {
+                    Self::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(I64: i64 => i8, i16, i32, i64, isize);
+impl_from_integer!(U64: u64 => u8, u16, u32, u64, usize);
+impl_from_integer!(F64: f64 => f32, f64);
+
+impl From<&Value> for Value {
+    fn from(value: &Value) -> Self {
+        value.clone()
+    }
+}
+
+impl From<()> for Value {
+    fn from(_: ()) -> Self {
+        Self::Null
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<Cow<'_, str>> for Value {
+    fn from(value: Cow<'_, str>) -> Self {
+        Self::String(value.into_owned())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Self::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Clone + Into<Value>> From<&[T]> for Value {
+    fn from(values: &[T]) -> Self {
+        Self::List(values.iter().cloned().map(Into::into).collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> From<BTreeMap<K, V>> for Value {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>, S> From<HashMap<K, V, S>> for Value {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<T: Into<Value>> FromIterator<T> for Value {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::List(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for Value {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::Map(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_from_succeeds() {
+        assert_eq!(Value::from(()), Value::Null);
+        assert_eq!(Value::from(true), Value::Bool(true));
+        assert_eq!(Value::from(-1i8), Value::I64(-1));
+        assert_eq!(Value::from(1usize), Value::U64(1));
+        assert_eq!(Value::from(0.5f32), Value::F64(0.5));
+        assert_eq!(Value::from('c'), Value::String("c".into()));
+        assert_eq!(Value::from(Some("text")), Value::String("text".into()));
+        assert_eq!(
+            Value::from(&[1, 2][..]),
+            Value::List(vec![Value::I64(1), Value::I64(2)])
+        );
+        assert_eq!(
+            Value::from(HashMap::from([("key", 1u8)])),
+            Value::Map(BTreeMap::from([("key".into(), Value::U64(1))]))
+        );
+    }
+
+    #[test]
+    fn value_accessor_succeeds() {
+        assert!(Value::Null.is_null());
+        assert_eq!(Value::from(true).as_bool(), Some(true));
+        assert_eq!(Value::from(1u64).as_i64(), Some(1));
+        assert_eq!(Value::from(u64::MAX).as_i64(), None);
+        assert_eq!(Value::from(-1).as_u64(), None);
+        assert_eq!(Value::from(1).as_f64(), Some(1.0));
+        assert_eq!(Value::from("text").as_str(), Some("text"));
+        assert_eq!(Value::from(vec![1]).as_list(), Some(&[Value::I64(1)][..]));
+        assert!(Value::from(BTreeMap::<String, i32>::new())
+            .as_map()
+            .is_some());
+    }
+
+    #[test]
+    fn value_display_succeeds() {
+        assert_eq!(Value::Null.to_string(), "null");
+        assert_eq!(Value::from(false).to_string(), "false");
+        assert_eq!(Value::from(-42).to_string(), "-42");
+        assert_eq!(Value::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Value::from(1.0).to_string(), "1.0");
+        assert_eq!(Value::from(f64::NAN).to_string(), "null");
+        assert_eq!(
+            Value::from("\"quoted\"\n\u{1}").to_string(),
+            r#""\"quoted\"\n\u0001""#
+        );
+
+        let value = Value::from_iter([
+            ("list", Value::from(vec![Value::from(1), Value::Null])),
+            ("map", Value::from_iter([("key", "value")])),
+        ]);
+        assert_eq!(
+            value.to_json(),
+            r#"{"list":[1,null],"map":{"key":"value"}}"#
+        );
+    }
+}
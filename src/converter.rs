@@ -1,6 +1,6 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
-use crate::context::AnyValue;
+use crate::context::{AnyValue, Value};
 
 /// The trait used to select the method to transforming values.
 ///
@@ -21,6 +21,14 @@ pub struct DebugConverter;
 
 impl Converter for DebugConverter {}
 
+/// A converter that uses the [`Display`] trait to format values, which
+/// presents values the way a user would type them, e.g. strings are neither
+/// quoted nor escaped.
+#[derive(Debug)]
+pub struct DisplayConverter;
+
+impl Converter for DisplayConverter {}
+
 /// A converter that uses the [`Into`] trait to convert values into another
 /// type.
 #[derive(Debug)]
@@ -35,6 +43,13 @@ pub struct BoxConverter;
 
 impl Converter for BoxConverter {}
 
+/// A converter that converts values into a structured [`Value`] through the
+/// [`Into`] trait.
+#[derive(Debug)]
+pub struct ValueConverter;
+
+impl Converter for ValueConverter {}
+
 /// The trait marking a type that is able to be converted to another one using
 /// a [`Converter`].
 ///
@@ -61,6 +76,12 @@ impl<S: Debug, T: From<String>> Convertable<DebugConverter, T> for S {
     }
 }
 
+impl<S: Display, T: From<String>> Convertable<DisplayConverter, T> for S {
+    fn to(self) -> T {
+        self.to_string().into()
+    }
+}
+
 impl<S: Into<T>, T> Convertable<IntoConverter, T> for S {
     fn to(self) -> T {
         self.into()
@@ -78,6 +99,16 @@ where
     }
 }
 
+impl<S, T> Convertable<ValueConverter, T> for S
+where
+    S: Into<Value>,
+    T: From<Value>,
+{
+    fn to(self) -> T {
+        T::from(self.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +123,9 @@ mod tests {
             r#""1""#
         );
 
+        assert_eq!(<_ as Convertable<DisplayConverter, String>>::to(1), "1");
+        assert_eq!(<_ as Convertable<DisplayConverter, String>>::to("1"), "1");
+
         assert_eq!(
             <_ as Convertable<IntoConverter, String>>::to("str"),
             String::from("str")
@@ -99,6 +133,11 @@ mod tests {
 
         let res = <_ as Convertable<BoxConverter, BoxAnyValue>>::to("1");
         assert_eq!(format!("{res:?}"), "\"1\"");
+
+        assert_eq!(
+            <_ as Convertable<ValueConverter, Value>>::to(1),
+            Value::I64(1)
+        );
     }
 
     #[test]
@@ -1,4 +1,6 @@
 mod data;
+#[cfg(feature = "tracing")]
+mod record;
 
 use std::any::{Any, TypeId};
 use std::backtrace::Backtrace;
@@ -6,13 +8,21 @@ use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
+use std::iter;
 use std::mem::{self, ManuallyDrop};
 
-use crate::context::{AbstractContext, Context, Entry};
+#[cfg(feature = "tracing")]
+use tracing_error::SpanTrace;
+
+use crate::ambient::{self, AmbientContext, Scope};
+use crate::code::ErrorCode;
+use crate::context::{AbstractContext, Context, Entry, MergePolicy, MergedContext};
 use crate::converter::Convertable;
+use crate::diagnostic::SourceSpan;
 use crate::kind::Kind;
+use crate::report::{self, DebugStyle, Renderer};
 
-use data::{ErrorData, ErrorDataBuilder};
+use data::{Attachments, ErrorData, ErrorDataBuilder};
 
 /// The central type for general error handling and reporting.
 ///
@@ -35,8 +45,15 @@ use data::{ErrorData, ErrorDataBuilder};
 /// which contains another error is often produced by the builder or the
 /// [`Overlay`] trait.
 ///
+/// The [`Debug`] implementation of [`AnyError`] renders it as a pretty
+/// [`Report`] does by default, which can be customized through
+/// [`set_hook()`]. Since context entries aren't required to implement
+/// [`Display`], they're printed there with [`Entry::fmt_entry()`], which the
+/// built-in entries implement in the same way as their [`Display`].
+///
 /// [`Overlay`]: `crate::overlay::Overlay`
-#[derive(Debug)]
+/// [`Report`]: `crate::report::Report`
+/// [`set_hook()`]: `crate::report::set_hook`
 pub struct AnyError<C, K>(Box<ErrorData<C, K>>)
 where
     C: AbstractContext,
@@ -65,7 +82,10 @@ where
             kind: K::default(),
             message: message.into(),
             backtrace: Backtrace::capture(),
-            context: C::default(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::capture(),
+            context: ambient::context(),
+            attachments: Attachments::default(),
         })
     }
 
@@ -88,7 +108,10 @@ where
             kind,
             message: message.into(),
             backtrace: Backtrace::capture(),
-            context: C::default(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::capture(),
+            context: ambient::context(),
+            attachments: Attachments::default(),
         })
     }
 
@@ -96,6 +119,9 @@ where
     /// Note that if this error is already an [`AnyError`], it'll be returned
     /// directly.
     ///
+    /// The kind of the new error is given by [`Kind::classify()`], or is
+    /// [`Kind::RAW_KIND`] if the error can't be classified.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -103,24 +129,77 @@ where
     /// # use anyerr::kind::DefaultErrorKind;
     /// # use anyerr::context::LiteralKeyStringMapContext;
     /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
-    /// let err = "not i32".parse::<i32>().map_err(AnyError::wrap);
-    /// // do anything with `err` in a universal fashion on the fly ...
+    /// let err = "not i32".parse::<i32>().map_err(AnyError::wrap).unwrap_err();
+    /// assert_eq!(err.kind(), DefaultErrorKind::ValueValidation);
     /// ```
     pub fn wrap<E>(err: E) -> Self
     where
         E: Error + Any + Send + Sync + 'static,
     {
+        match Self::try_cast(err) {
+            Ok(err) => err,
+            Err(err) => {
+                let kind = K::classify(&err).unwrap_or(K::RAW_KIND);
+                Self::wrap_foreign(err, kind)
+            }
+        }
+    }
+
+    /// Wraps an arbitrary error in an [`AnyError`] with a backtrace attached,
+    /// whose kind is `kind` regardless of how the error is classified. Note
+    /// that if this error is already an [`AnyError`], it'll be returned with
+    /// the kind of its outermost layer replaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = "not i32"
+    ///     .parse::<i32>()
+    ///     .map_err(|err| AnyError::wrap_with_kind(err, DefaultErrorKind::RuleViolation))
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), DefaultErrorKind::RuleViolation);
+    /// ```
+    pub fn wrap_with_kind<E>(err: E, kind: K) -> Self
+    where
+        E: Error + Any + Send + Sync + 'static,
+    {
+        match Self::try_cast(err) {
+            Ok(mut err) => {
+                err.0.set_kind(kind);
+                err
+            }
+            Err(err) => Self::wrap_foreign(err, kind),
+        }
+    }
+
+    /// Returns `err` itself if it's already an [`AnyError`] of this type.
+    fn try_cast<E: Any>(err: E) -> Result<Self, E> {
         if TypeId::of::<E>() == TypeId::of::<Self>() {
             // SAFETY: we already checked `E` is actually `Self`
-            unsafe { mem::transmute_copy(&ManuallyDrop::new(err)) }
+            Ok(unsafe { mem::transmute_copy(&ManuallyDrop::new(err)) })
         } else {
-            Self::from(ErrorData::Wrapped {
-                backtrace: Backtrace::capture(),
-                inner: Box::new(err),
-            })
+            Err(err)
         }
     }
 
+    fn wrap_foreign<E>(err: E, kind: K) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self::from(ErrorData::Wrapped {
+            kind,
+            backtrace: Backtrace::capture(),
+            #[cfg(feature = "tracing")]
+            span_trace: SpanTrace::capture(),
+            context: ambient::context(),
+            inner: Box::new(err),
+        })
+    }
+
     /// Returns a dedicated builder [`AnyErrorBuilder`] to instantiate an
     /// [`AnyError`].
     pub fn builder() -> AnyErrorBuilder<C, K> {
@@ -132,7 +211,215 @@ where
         self.0.kind()
     }
 
+    /// Returns true if the kind of any layer along the error chain is `kind`
+    /// or one of its descendants according to [`Kind::is_a()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::kind::PathKind;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, PathKind>;
+    /// let err = AnyError::quick("timed out", PathKind::new("storage.io.timeout"))
+    ///     .overlay(("could not load the user", PathKind::new("user.load")))
+    ///     .build();
+    /// assert!(err.has_kind(PathKind::new("storage.io")));
+    /// assert!(err.has_kind(PathKind::new("user")));
+    /// assert!(!err.has_kind(PathKind::new("network")));
+    /// ```
+    pub fn has_kind(&self, kind: K) -> bool {
+        self.0.layer_kinds().any(|current| current.is_a(kind))
+    }
+
+    /// Returns true if the operation which failed with this error may succeed
+    /// when it's retried.
+    ///
+    /// The kinds along the error chain are checked from the outermost layer
+    /// to the innermost one, and the first kind which is neither
+    /// [`Kind::RAW_KIND`] nor [`Kind::UNKNOWN_KIND`] decides the result via
+    /// [`Kind::is_retryable()`]. This error is not retryable if no such kind
+    /// is found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::quick("connection reset", DefaultErrorKind::InfrastructureFailure)
+    ///     .overlay("could not load the user")
+    ///     .build();
+    /// assert!(err.is_retryable());
+    ///
+    /// let err = err.overlay(("invalid user", DefaultErrorKind::ValueValidation)).build();
+    /// assert!(!err.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        self.specified_kind()
+            .is_some_and(|kind| kind.is_retryable())
+    }
+
+    /// Returns the stable code of this error.
+    ///
+    /// The layers are checked from the outermost one to the innermost one,
+    /// and the first code found is returned, where a code attached through
+    /// [`AnyErrorBuilder::error_code()`] takes precedence over the one given by
+    /// [`Kind::error_code()`] for the same layer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::code::ErrorCode;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// const E1042: ErrorCode = ErrorCode::new("E1042", "the account is locked");
+    /// let err = AnyError::builder()
+    ///     .message("account 42 is locked")
+    ///     .error_code(E1042)
+    ///     .build()
+    ///     .overlay("could not log in")
+    ///     .build();
+    /// assert_eq!(err.error_code(), Some(E1042));
+    /// ```
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.0.error_code()
+    }
+
+    /// Returns the help messages attached to this error, from the outermost
+    /// layer to the innermost one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("the file already exists")
+    ///     .help("try `--force` to overwrite it")
+    ///     .build()
+    ///     .overlay("could not save the file")
+    ///     .help("choose another path")
+    ///     .note("the file is saved as `out.txt` by default")
+    ///     .build();
+    /// assert_eq!(
+    ///     err.helps().collect::<Vec<_>>(),
+    ///     ["choose another path", "try `--force` to overwrite it"]
+    /// );
+    /// assert_eq!(
+    ///     err.notes().collect::<Vec<_>>(),
+    ///     ["the file is saved as `out.txt` by default"]
+    /// );
+    /// ```
+    pub fn helps(&self) -> impl Iterator<Item = &str> {
+        self.0.helps()
+    }
+
+    /// Returns the notes attached to this error, from the outermost layer to
+    /// the innermost one.
+    pub fn notes(&self) -> impl Iterator<Item = &str> {
+        self.0.notes()
+    }
+
+    /// Returns the source spans attached to this error, from the outermost
+    /// layer to the innermost one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::diagnostic::SourceSpan;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("invalid port")
+    ///     .source_span(SourceSpan::new("config.toml", "port = \"eighty\"", 7..15))
+    ///     .build();
+    /// let span = err.source_spans().next().unwrap();
+    /// assert_eq!(span.source_name(), "config.toml");
+    /// assert_eq!(span.range(), 7..15);
+    /// ```
+    pub fn source_spans(&self) -> impl Iterator<Item = &SourceSpan> {
+        self.0.source_spans()
+    }
+
+    /// Collects the source spans attached to this error into a
+    /// [`Value::List`], from the outermost layer to the innermost one, where
+    /// each span is converted by [`SourceSpan::to_value()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::diagnostic::SourceSpan;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("invalid port")
+    ///     .source_span(SourceSpan::new("config.toml", "port = \"eighty\"", 7..15))
+    ///     .build();
+    /// assert_eq!(
+    ///     err.source_spans_value().to_json(),
+    ///     concat!(
+    ///         r#"[{"column":8,"end":15,"label":null,"line":1,"#,
+    ///         r#""source_name":"config.toml","start":7}]"#
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Value::List`]: crate::context::Value::List
+    pub fn source_spans_value(&self) -> crate::context::Value {
+        crate::context::Value::List(self.source_spans().map(SourceSpan::to_value).collect())
+    }
+
+    /// Returns the source spans attached to the outermost layer only.
+    pub(crate) fn layer_source_spans(&self) -> &[SourceSpan] {
+        self.0.layer_source_spans()
+    }
+
+    /// Returns the first kind along the error chain which is neither
+    /// [`Kind::RAW_KIND`] nor [`Kind::UNKNOWN_KIND`].
+    pub(crate) fn specified_kind(&self) -> Option<K> {
+        self.specified_layer().map(Self::kind)
+    }
+
+    /// Returns the outermost layer whose kind is neither [`Kind::RAW_KIND`]
+    /// nor [`Kind::UNKNOWN_KIND`].
+    pub(crate) fn specified_layer(&self) -> Option<&Self> {
+        // Each layer but the outermost one is the source of the layer above.
+        let mut layers =
+            iter::once(self).chain(self.0.layers().filter_map(ErrorData::layer_source));
+        layers.find(|layer| {
+            let kind = layer.kind();
+            !kind.is_raw() && !kind.is_unknown()
+        })
+    }
+
     /// Returns the error message of this error.
+    ///
+    /// Note that this is the same as the [`Display`] output of the error,
+    /// which only includes the outermost error message, while the alternate
+    /// form `{:#}` renders the whole chain of causes, separated by `": "`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::minimal("inner").overlay("outer").build();
+    /// assert_eq!(err.message(), "outer");
+    /// assert_eq!(format!("{err}"), "outer");
+    /// assert_eq!(format!("{err:#}"), "outer: inner");
+    /// ```
     pub fn message(&self) -> String {
         self.0.message()
     }
@@ -142,12 +429,88 @@ where
         self.0.backtrace()
     }
 
-    /// Returns an iterator which iterates over either all attached context
-    /// or those added to the most outer error.
+    /// Returns the span trace captured where the deepest error occurred,
+    /// which is only available with the `tracing` feature enabled.
+    ///
+    /// The span trace is empty unless the current subscriber has the
+    /// [`ErrorLayer`] installed.
+    ///
+    /// [`ErrorLayer`]: https://docs.rs/tracing-error/latest/tracing_error/struct.ErrorLayer.html
+    #[cfg(feature = "tracing")]
+    pub fn span_trace(&self) -> &SpanTrace {
+        self.0.span_trace()
+    }
+
+    /// Returns an iterator which iterates over the context attached to the
+    /// layers selected by `depth`, from the outermost layer to the innermost
+    /// one.
     pub fn context(&self, depth: ContextDepth) -> C::Iter<'_> {
         self.0.context(depth)
     }
 
+    /// Returns an iterator which yields the context attached to the layers
+    /// selected by `depth`, along with the depth of the layer where each entry
+    /// comes from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::core::ContextDepth;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("key1", 1)
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .context("key2", 2)
+    ///     .build();
+    /// let entries = err
+    ///     .indexed_context(ContextDepth::All)
+    ///     .map(|(depth, entry)| format!("{depth}: {entry}"))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(entries, ["0: key2 = 2", "1: key1 = 1"]);
+    /// ```
+    pub fn indexed_context(&self, depth: ContextDepth) -> IndexedContext<'_, C, K> {
+        IndexedContext::new(self, depth)
+    }
+
+    /// Returns the effective view of the context attached to all layers,
+    /// where an entry attached to an outer layer shadows those of the same key
+    /// attached to inner layers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("client-addr", "127.0.0.1")
+    ///     .context("path", "/")
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .context("client-addr", "localhost")
+    ///     .build();
+    /// let context = err.merged_context();
+    /// assert_eq!(context.len(), 2);
+    /// assert_eq!(context.get("client-addr"), Some("\"localhost\""));
+    /// assert_eq!(context.get("path"), Some("\"/\""));
+    /// ```
+    pub fn merged_context(&self) -> MergedContext<'_, C::Entry> {
+        self.merged_context_with(MergePolicy::OutermostWins)
+    }
+
+    /// Returns the view of the context attached to all layers, where entries
+    /// sharing the same key are merged according to `policy`.
+    pub fn merged_context_with(&self, policy: MergePolicy) -> MergedContext<'_, C::Entry> {
+        policy.merge(self.context(ContextDepth::All))
+    }
+
     /// Returns true if the inner type is the same as `E`. Note that the error
     /// is not equivalent to the source error, which stands for the current
     /// [`AnyError`]'s cause, while the former means the external error type
@@ -184,10 +547,23 @@ where
                     Err(self)
                 }
             }
-            ErrorData::Wrapped { inner, backtrace } => inner
-                .downcast::<E>()
-                .map(|res| *res)
-                .map_err(|inner| Self::from(ErrorData::Wrapped { backtrace, inner })),
+            ErrorData::Wrapped {
+                kind,
+                backtrace,
+                #[cfg(feature = "tracing")]
+                span_trace,
+                context,
+                inner,
+            } => inner.downcast::<E>().map(|res| *res).map_err(|inner| {
+                Self::from(ErrorData::Wrapped {
+                    kind,
+                    backtrace,
+                    #[cfg(feature = "tracing")]
+                    span_trace,
+                    context,
+                    inner,
+                })
+            }),
         }
     }
 
@@ -223,6 +599,62 @@ where
     }
 }
 
+impl<C, K> AnyError<C, K>
+where
+    C: Context,
+    K: Kind,
+{
+    /// Returns a new [`Scope`], through which ambient context can be attached
+    /// to errors of this type created on the current thread.
+    pub fn scope() -> Scope<C> {
+        Scope::new()
+    }
+
+    /// Returns a new [`AmbientContext`], which can be installed on any thread
+    /// or attached to a future as the ambient context of errors of this type.
+    pub fn ambient_context() -> AmbientContext<C> {
+        AmbientContext::new()
+    }
+
+    /// Returns a mutable reference to the context of the outermost layer,
+    /// through which context can be added without wrapping this error with a
+    /// new layer.
+    pub fn context_mut(&mut self) -> &mut C {
+        self.0.layer_context_mut()
+    }
+
+    /// Attaches some context represented as a key-value pair to the outermost
+    /// layer of this error. Unlike [`Overlay::overlay()`], no new layer is
+    /// created and the error message is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = "-1"
+    ///     .parse::<u32>()
+    ///     .map_err(AnyError::wrap)
+    ///     .map_err(|err| err.attach_context("text", "-1"))
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid digit found in string");
+    /// assert_eq!(err.get("text"), Some("\"-1\""));
+    /// ```
+    ///
+    /// [`Overlay::overlay()`]: crate::overlay::Overlay::overlay
+    pub fn attach_context<Q, R>(mut self, key: Q, value: R) -> Self
+    where
+        Q: Into<C::Key>,
+        R: Convertable<C::Converter, C::Value>,
+    {
+        self.context_mut()
+            .insert_with::<C::Converter, _, _>(key, value);
+        self
+    }
+}
+
 impl<C, K> AnyError<C, K>
 where
     C: crate::context::SingletonContext,
@@ -278,7 +710,55 @@ where
         <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
         Q: Debug + Eq + Hash + ?Sized,
     {
-        self.0.get(key)
+        self.get_with_depth(key, ContextDepth::Shallowest)
+    }
+
+    /// Returns the context information carried by the nearest layer within
+    /// `depth` which has an entry of `key`, where `C` is a [`StringContext`].
+    ///
+    /// [`StringContext`]: `crate::context::StringContext`
+    pub fn get_with_depth<Q>(
+        &self,
+        key: &Q,
+        depth: ContextDepth,
+    ) -> Option<&<C::Entry as Entry>::ValueBorrowed>
+    where
+        <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        self.0.get_with_depth(key, depth)
+    }
+
+    /// Returns the context information carried by the nearest layer along the
+    /// error chain which has an entry of `key`, where `C` is a
+    /// [`StringContext`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("request-id", 42)
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .context("path", "/")
+    ///     .build();
+    /// assert_eq!(err.get("request-id"), None);
+    /// assert_eq!(err.get_deep("request-id"), Some("42"));
+    /// assert_eq!(err.get_deep("path"), Some("\"/\""));
+    /// ```
+    ///
+    /// [`StringContext`]: `crate::context::StringContext`
+    pub fn get_deep<Q>(&self, key: &Q) -> Option<&<C::Entry as Entry>::ValueBorrowed>
+    where
+        <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+    {
+        self.get_with_depth(key, ContextDepth::All)
     }
 }
 
@@ -314,7 +794,92 @@ where
         Q: Debug + Eq + Hash + ?Sized,
         T: Any,
     {
-        self.0.value_as::<T, _>(key)
+        self.value_as_with_depth::<T, _>(key, ContextDepth::Shallowest)
+    }
+
+    /// Returns the context information carried by the nearest layer within
+    /// `depth` which has an entry of `key` and then attempts to convert the
+    /// result to a `T`, where `C` is a [`AnyContext`].
+    ///
+    /// [`AnyContext`]: `crate::context::AnyContext`
+    pub fn value_as_with_depth<T, Q>(&self, key: &Q, depth: ContextDepth) -> Option<&T>
+    where
+        <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+        T: Any,
+    {
+        self.0.value_as_with_depth::<T, _>(key, depth)
+    }
+
+    /// Returns the context information carried by the nearest layer along the
+    /// error chain which has an entry of `key` and then attempts to convert
+    /// the result to a `T`, where `C` is a [`AnyContext`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyAnyMapContext;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyAnyMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("request-id", 42)
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .build();
+    /// assert_eq!(err.value_as::<i32, _>("request-id"), None);
+    /// assert_eq!(err.value_as_deep::<i32, _>("request-id"), Some(&42));
+    /// ```
+    ///
+    /// [`AnyContext`]: `crate::context::AnyContext`
+    pub fn value_as_deep<T, Q>(&self, key: &Q) -> Option<&T>
+    where
+        <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
+        Q: Debug + Eq + Hash + ?Sized,
+        T: Any,
+    {
+        self.value_as_with_depth::<T, _>(key, ContextDepth::All)
+    }
+}
+
+impl<C, K> AnyError<C, K>
+where
+    C: crate::context::ValueContext,
+    K: Kind,
+{
+    /// Collects the context attached to the layers selected by `depth` into a
+    /// [`Value::Map`], where `C` is a [`ValueContext`]. If multiple entries
+    /// share the same key, the one from the outermost layer is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::context::LiteralKeyValueMapContext;
+    /// # use anyerr::core::ContextDepth;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyValueMapContext, DefaultErrorKind>;
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("id", 42)
+    ///     .context("tags", vec!["a", "b"])
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .context("id", 43)
+    ///     .build();
+    /// assert_eq!(
+    ///     err.context_value(ContextDepth::All).to_json(),
+    ///     r#"{"id":43,"tags":["a","b"]}"#
+    /// );
+    /// ```
+    ///
+    /// [`Value::Map`]: crate::context::Value::Map
+    /// [`ValueContext`]: crate::context::ValueContext
+    pub fn context_value(&self, depth: ContextDepth) -> crate::context::Value {
+        MergePolicy::OutermostWins
+            .merge(self.context(depth))
+            .to_value()
     }
 }
 
@@ -328,6 +893,19 @@ where
     }
 }
 
+impl<C, K> Debug for AnyError<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match report::debug_style(self) {
+            DebugStyle::Report(options) => Renderer::with_fmt_entry(self, &options).render(f),
+            DebugStyle::Raw => f.debug_tuple("AnyError").field(&self.0).finish(),
+        }
+    }
+}
+
 impl<C, K> Display for AnyError<C, K>
 where
     C: AbstractContext,
@@ -348,10 +926,121 @@ where
     }
 }
 
+/// Selects the layers of an [`AnyError`] whose context is visited.
+///
+/// Each time an [`AnyError`] is overlaid, a new layer is put on top of it.
+/// The depth of the outermost layer is 0, and the depth of the layer below a
+/// layer of depth `n` is `n + 1`.
+///
+/// More selections may be added in the future, so matching on this enum
+/// requires a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ContextDepth {
+    /// All layers.
     All,
+    /// The outermost layer only.
     Shallowest,
+    /// The innermost layer only, which is where the root cause occurred.
+    Deepest,
+    /// The layer of the given depth only.
+    Nth(usize),
+    /// The layers whose depth is less than or equal to the given one.
+    UpTo(usize),
+    /// The layers whose depth is in the range of `start..end`.
+    Range {
+        /// The inclusive lower bound of the depth.
+        start: usize,
+        /// The exclusive upper bound of the depth.
+        end: usize,
+    },
+}
+
+impl ContextDepth {
+    /// Returns `true` if the layer of `depth` is selected, where `deepest` is
+    /// the depth of the innermost layer.
+    pub(crate) fn contains(self, depth: usize, deepest: usize) -> bool {
+        match self {
+            Self::All => true,
+            Self::Shallowest => depth == 0,
+            Self::Deepest => depth == deepest,
+            Self::Nth(n) => depth == n,
+            Self::UpTo(n) => depth <= n,
+            Self::Range { start, end } => (start..end).contains(&depth),
+        }
+    }
+}
+
+/// An iterator over the context of an [`AnyError`] which yields each entry
+/// along with the depth of the layer it's attached to.
+///
+/// This struct is created by [`AnyError::indexed_context()`].
+pub struct IndexedContext<'a, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    next: Option<&'a ErrorData<C, K>>,
+    next_depth: usize,
+    deepest: usize,
+    selection: ContextDepth,
+    current: Option<(usize, C::Iter<'a>)>,
+}
+
+impl<'a, C, K> IndexedContext<'a, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn new(error: &'a AnyError<C, K>, selection: ContextDepth) -> Self {
+        Self {
+            next: Some(&error.0),
+            next_depth: 0,
+            deepest: error.0.deepest(),
+            selection,
+            current: None,
+        }
+    }
+}
+
+impl<'a, C, K> Iterator for IndexedContext<'a, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Item = (usize, &'a C::Entry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((depth, iter)) = &mut self.current {
+                if let Some(entry) = iter.next() {
+                    return Some((*depth, entry));
+                }
+                self.current = None;
+            }
+            let layer = self.next.take()?;
+            let depth = self.next_depth;
+            self.next = layer.layer_source().map(|source| &*source.0);
+            self.next_depth += 1;
+            if self.selection.contains(depth, self.deepest) {
+                self.current = Some((depth, layer.layer_context_iter()));
+            }
+        }
+    }
+}
+
+impl<C, K> Debug for IndexedContext<'_, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("IndexedContext")
+            .field("next_depth", &self.next_depth)
+            .field("deepest", &self.deepest)
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
 }
 
 /// The builder of [`AnyError`].
@@ -410,6 +1099,31 @@ where
         Self(self.0.source(source))
     }
 
+    /// Attaches a stable error code to the resulting error, which takes
+    /// precedence over the one given by [`Kind::error_code()`].
+    pub fn error_code(self, code: ErrorCode) -> Self {
+        Self(self.0.error_code(code))
+    }
+
+    /// Attaches a help message, which tells how to fix the error, to the
+    /// resulting error. Multiple help messages can be attached.
+    pub fn help<S: Into<String>>(self, help: S) -> Self {
+        Self(self.0.help(help))
+    }
+
+    /// Attaches a note, which explains the error in more detail, to the
+    /// resulting error. Multiple notes can be attached.
+    pub fn note<S: Into<String>>(self, note: S) -> Self {
+        Self(self.0.note(note))
+    }
+
+    /// Attaches a [`SourceSpan`], which points at where the error occurs in
+    /// some source text, to the resulting error. Multiple spans can be
+    /// attached.
+    pub fn source_span(self, span: SourceSpan) -> Self {
+        Self(self.0.source_span(span))
+    }
+
     /// Returns the error with the provided data for each fields.
     pub fn build(self) -> AnyError<C, K> {
         AnyError::from(self.0.build())
@@ -434,6 +1148,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::fmt;
+    use std::io;
     use std::num::ParseIntError;
 
     use crate::context::StringKeyStringMapContext;
@@ -478,6 +1194,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn any_error_wrap_keeps_kind_of_any_error() {
+        let inner = DefaultAnyError::quick("error", DefaultErrorKind::EntityAbsence);
+        let err = DefaultAnyError::wrap(inner);
+        assert_eq!(err.kind(), DefaultErrorKind::EntityAbsence);
+        assert_eq!(err.to_string(), "error");
+
+        let res: Result<(), _> = Err(DefaultAnyError::quick(
+            "error",
+            DefaultErrorKind::ValueValidation,
+        ));
+        let err = res.map_err(DefaultAnyError::wrap).unwrap_err();
+        assert_eq!(err.kind(), DefaultErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn any_error_wrap_classifies_kind() {
+        let err = DefaultAnyError::wrap("".parse::<u32>().unwrap_err());
+        assert_eq!(err.kind(), DefaultErrorKind::ValueValidation);
+
+        let err = DefaultAnyError::wrap(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.kind(), DefaultErrorKind::EntityAbsence);
+
+        let err = DefaultAnyError::wrap(io::Error::from(io::ErrorKind::TimedOut));
+        assert_eq!(err.kind(), DefaultErrorKind::InfrastructureFailure);
+        assert!(err.is_retryable());
+
+        let err = DefaultAnyError::wrap(io::Error::other("other"));
+        assert_eq!(err.kind(), DefaultErrorKind::Raw);
+
+        let err = DefaultAnyError::wrap(fmt::Error);
+        assert_eq!(err.kind(), DefaultErrorKind::Raw);
+    }
+
+    #[test]
+    fn any_error_wrap_with_kind_succeeds() {
+        let inner = "".parse::<u32>().unwrap_err();
+        let err = DefaultAnyError::wrap_with_kind(inner, DefaultErrorKind::RuleViolation);
+        assert_eq!(err.kind(), DefaultErrorKind::RuleViolation);
+        assert!(err.downcast_ref::<ParseIntError>().is_some());
+
+        let inner = DefaultAnyError::minimal("error");
+        let err = DefaultAnyError::wrap_with_kind(inner, DefaultErrorKind::EntityAbsence);
+        assert_eq!(err.kind(), DefaultErrorKind::EntityAbsence);
+        assert_eq!(err.to_string(), "error");
+        assert!(err.source().is_none());
+    }
+
     #[test]
     fn any_error_downcast_succeeds() {
         {
@@ -492,6 +1256,7 @@ mod tests {
                 kind: DefaultErrorKind::Unknown,
                 message: "error".into(),
                 context: StringKeyStringMapContext::new(),
+                attachments: Attachments::default(),
                 source,
             });
             assert!(err.downcast_ref::<DefaultAnyError>().is_some());
@@ -507,6 +1272,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn any_error_is_retryable_succeeds() {
+        let err = DefaultAnyError::wrap("".parse::<u32>().unwrap_err());
+        assert!(!err.is_retryable());
+
+        let err = DefaultAnyError::builder()
+            .kind(DefaultErrorKind::InfrastructureFailure)
+            .message("inner")
+            .source(err)
+            .build();
+        assert!(err.is_retryable());
+
+        let err = DefaultAnyError::builder()
+            .message("outer")
+            .source(err)
+            .build();
+        assert!(err.is_retryable());
+
+        let err = DefaultAnyError::builder()
+            .kind(DefaultErrorKind::RuleViolation)
+            .message("outermost")
+            .source(err)
+            .build();
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn any_error_get_deep_succeeds() {
+        let err = DefaultAnyError::builder()
+            .message("inner")
+            .context("request-id", "42")
+            .context("key", "inner")
+            .source(DefaultAnyError::wrap("".parse::<u32>().unwrap_err()))
+            .build();
+        let err = DefaultAnyError::builder()
+            .message("middle")
+            .context("key", "middle")
+            .source(err)
+            .build();
+        let err = DefaultAnyError::builder()
+            .message("outer")
+            .source(err)
+            .build();
+
+        assert_eq!(err.get("request-id"), None);
+        assert_eq!(err.get_deep("request-id"), Some("\"42\""));
+        assert_eq!(err.get_deep("key"), Some("\"middle\""));
+        assert_eq!(err.get_deep("absent"), None);
+        assert_eq!(err.get_with_depth("key", ContextDepth::Shallowest), None);
+    }
+
+    #[test]
+    fn any_error_indexed_context_succeeds() {
+        let err = DefaultAnyError::builder()
+            .message("inner")
+            .context("key1", "1")
+            .context("key2", "2")
+            .build();
+        let err = DefaultAnyError::builder()
+            .message("middle")
+            .source(err)
+            .build();
+        let err = DefaultAnyError::builder()
+            .message("outer")
+            .context("key0", "0")
+            .source(err)
+            .build();
+
+        let entries = err
+            .indexed_context(ContextDepth::All)
+            .map(|(depth, entry)| (depth, entry.key()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, [(0, "key0"), (2, "key1"), (2, "key2")]);
+
+        let entries = err
+            .indexed_context(ContextDepth::Range { start: 1, end: 2 })
+            .collect::<Vec<_>>();
+        assert!(entries.is_empty());
+
+        let entries = err
+            .indexed_context(ContextDepth::Deepest)
+            .map(|(depth, entry)| (depth, entry.key()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, [(2, "key1"), (2, "key2")]);
+    }
+
+    #[test]
+    fn any_error_source_spans_value_succeeds() {
+        let text = "[server]\nport = \"eighty\"\n";
+        let source = DefaultAnyError::builder()
+            .message("invalid port")
+            .source_span(SourceSpan::new("config.toml", text, 16..24).with_label("not a number"))
+            .build();
+        let err = DefaultAnyError::builder()
+            .message("invalid server section")
+            .source(source)
+            .source_span(SourceSpan::new("config.toml", text, 0..8))
+            .build();
+        assert_eq!(
+            err.source_spans_value().to_json(),
+            concat!(
+                r#"[{"column":1,"end":8,"label":null,"line":1,"#,
+                r#""source_name":"config.toml","start":0},"#,
+                r#"{"column":8,"end":24,"label":"not a number","line":2,"#,
+                r#""source_name":"config.toml","start":16}]"#
+            )
+        );
+        assert_eq!(
+            DefaultAnyError::minimal("error")
+                .source_spans_value()
+                .to_json(),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn any_error_debug_renders_report_by_default() {
+        let err = DefaultAnyError::builder()
+            .kind(DefaultErrorKind::ValueValidation)
+            .message("error")
+            .context("key", "value")
+            .build();
+        let mut expected = String::new();
+        expected.push_str("Error:\n");
+        expected.push_str("    (ValueValidation) error\n");
+        expected.push_str("    [key = \"value\"]\n");
+        expected.push_str("\nStack backtrace:\n");
+        assert!(format!("{err:?}").starts_with(&expected));
+    }
+
+    #[test]
+    fn any_error_debug_succeeds_with_entries_without_display() {
+        use crate::context::map::{MapContext, MapEntry};
+        use crate::converter::IntoConverter;
+
+        type NumberContext = MapContext<MapEntry<&'static str, str, i32, i32>, IntoConverter>;
+
+        let err = AnyError::<NumberContext, DefaultErrorKind>::builder()
+            .message("error")
+            .context("key", 42)
+            .build();
+        let mut expected = String::new();
+        expected.push_str("Error:\n");
+        expected.push_str("    (Unknown) error\n");
+        expected.push_str("    [key = 42]\n");
+        assert!(format!("{err:?}").starts_with(&expected));
+    }
+
     #[test]
     fn any_error_propagation_succeeds() {
         fn try_parse(val: &str) -> Result<u32, ParseIntError> {
@@ -4,10 +4,17 @@ use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
+use std::iter;
 
+#[cfg(feature = "tracing")]
+use tracing_error::SpanTrace;
+
+use crate::ambient;
+use crate::code::ErrorCode;
 use crate::context::{AbstractContext, Context, Entry, Iter};
 use crate::converter::Convertable;
 use crate::core::{AnyError, ContextDepth};
+use crate::diagnostic::SourceSpan;
 use crate::kind::Kind;
 
 #[derive(Debug)]
@@ -20,20 +27,37 @@ where
         kind: K,
         message: String,
         backtrace: Backtrace,
+        #[cfg(feature = "tracing")]
+        span_trace: SpanTrace,
         context: C,
+        attachments: Attachments,
     },
     Layered {
         kind: K,
         message: String,
         context: C,
+        attachments: Attachments,
         source: AnyError<C, K>,
     },
     Wrapped {
+        kind: K,
         backtrace: Backtrace,
+        #[cfg(feature = "tracing")]
+        span_trace: SpanTrace,
+        context: C,
         inner: Box<dyn Error + Send + Sync + 'static>,
     },
 }
 
+/// The diagnostics attached to a layer in addition to its context.
+#[derive(Debug, Default)]
+pub struct Attachments {
+    pub code: Option<ErrorCode>,
+    pub helps: Vec<String>,
+    pub notes: Vec<String>,
+    pub source_spans: Vec<SourceSpan>,
+}
+
 impl<C, K> ErrorData<C, K>
 where
     C: AbstractContext,
@@ -43,7 +67,15 @@ where
         match self {
             Self::Simple { kind, .. } => *kind,
             Self::Layered { kind, .. } => *kind,
-            Self::Wrapped { .. } => K::RAW_KIND,
+            Self::Wrapped { kind, .. } => *kind,
+        }
+    }
+
+    pub fn set_kind(&mut self, new_kind: K) {
+        match self {
+            Self::Simple { kind, .. } => *kind = new_kind,
+            Self::Layered { kind, .. } => *kind = new_kind,
+            Self::Wrapped { kind, .. } => *kind = new_kind,
         }
     }
 
@@ -63,18 +95,140 @@ where
         }
     }
 
+    #[cfg(feature = "tracing")]
+    pub fn span_trace(&self) -> &SpanTrace {
+        match self {
+            Self::Simple { span_trace, .. } => span_trace,
+            Self::Layered { source, .. } => source.span_trace(),
+            Self::Wrapped { span_trace, .. } => span_trace,
+        }
+    }
+
     pub fn context(&self, depth: ContextDepth) -> C::Iter<'_> {
+        let deepest = self.deepest();
+        self.layers()
+            .enumerate()
+            .filter(|(index, _)| depth.contains(*index, deepest))
+            .fold(C::Iter::default(), |iter, (_, layer)| {
+                iter.compose(layer.layer_context_iter())
+            })
+    }
+
+    /// Returns the context attached to this layer, excluding those attached
+    /// to its source.
+    pub fn layer_context(&self) -> &C {
         match self {
-            Self::Simple { context, .. } => context.iter(),
-            Self::Layered {
-                context, source, ..
-            } => match depth {
-                ContextDepth::All => context.iter().compose(source.context(depth)),
-                ContextDepth::Shallowest => context.iter(),
-            },
-            Self::Wrapped { .. } => C::Iter::default(),
+            Self::Simple { context, .. } => context,
+            Self::Layered { context, .. } => context,
+            Self::Wrapped { context, .. } => context,
+        }
+    }
+
+    pub fn layer_context_mut(&mut self) -> &mut C {
+        match self {
+            Self::Simple { context, .. } => context,
+            Self::Layered { context, .. } => context,
+            Self::Wrapped { context, .. } => context,
+        }
+    }
+
+    /// Returns the diagnostics attached to this layer, which are only
+    /// available for layers made by the builder or associative functions.
+    pub fn layer_attachments(&self) -> Option<&Attachments> {
+        match self {
+            Self::Simple { attachments, .. } => Some(attachments),
+            Self::Layered { attachments, .. } => Some(attachments),
+            Self::Wrapped { .. } => None,
+        }
+    }
+
+    /// Returns the first error code found from the outermost layer to the
+    /// innermost one, where a code attached to a layer takes precedence over
+    /// the one of its kind.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.layers().find_map(|layer| {
+            layer
+                .layer_attachments()
+                .and_then(|attachments| attachments.code)
+                .or_else(|| layer.kind().error_code())
+        })
+    }
+
+    /// Returns the help messages attached to all layers, from the outermost
+    /// layer to the innermost one.
+    pub fn helps(&self) -> impl Iterator<Item = &str> {
+        self.layers()
+            .filter_map(Self::layer_attachments)
+            .flat_map(|attachments| attachments.helps.iter().map(String::as_str))
+    }
+
+    /// Returns the notes attached to all layers, from the outermost layer to
+    /// the innermost one.
+    pub fn notes(&self) -> impl Iterator<Item = &str> {
+        self.layers()
+            .filter_map(Self::layer_attachments)
+            .flat_map(|attachments| attachments.notes.iter().map(String::as_str))
+    }
+
+    /// Returns the source spans attached to all layers, from the outermost
+    /// layer to the innermost one.
+    pub fn source_spans(&self) -> impl Iterator<Item = &SourceSpan> {
+        self.layers()
+            .filter_map(Self::layer_attachments)
+            .flat_map(|attachments| attachments.source_spans.iter())
+    }
+
+    /// Returns the source spans attached to this layer.
+    pub fn layer_source_spans(&self) -> &[SourceSpan] {
+        self.layer_attachments()
+            .map_or(&[], |attachments| &attachments.source_spans)
+    }
+
+    pub fn layer_context_iter(&self) -> C::Iter<'_> {
+        self.layer_context().iter()
+    }
+
+    /// Returns the next layer if this layer is built on top of an
+    /// [`AnyError`].
+    pub fn layer_source(&self) -> Option<&AnyError<C, K>> {
+        match self {
+            Self::Layered { source, .. } => Some(source),
+            _ => None,
         }
     }
+
+    /// Returns the kinds of all layers, from the outermost layer to the
+    /// innermost one.
+    pub fn layer_kinds(&self) -> impl Iterator<Item = K> + '_ {
+        self.layers().map(Self::kind)
+    }
+
+    /// Returns the depth of the innermost layer, where the outermost one's is
+    /// 0.
+    pub fn deepest(&self) -> usize {
+        self.layers().count() - 1
+    }
+
+    /// Returns all layers, from the outermost layer to the innermost one.
+    pub fn layers(&self) -> impl Iterator<Item = &Self> {
+        iter::successors(Some(self), |layer| {
+            layer.layer_source().map(|source| &*source.0)
+        })
+    }
+
+    /// Returns the first result produced by `find` on the context of each
+    /// layer within `depth`, from the outermost layer to the innermost one.
+    fn find_in_context<'a, T, F>(&'a self, depth: ContextDepth, find: F) -> Option<&'a T>
+    where
+        T: ?Sized,
+        F: Fn(&'a C) -> Option<&'a T>,
+    {
+        let deepest = self.deepest();
+        self.layers()
+            .enumerate()
+            .filter(|(index, _)| depth.contains(*index, deepest))
+            .find_map(|(_, layer)| find(layer.layer_context()))
+    }
 }
 
 impl<C, K> ErrorData<C, K>
@@ -86,7 +240,7 @@ where
         match self {
             Self::Simple { context, .. } => context.value(),
             Self::Layered { context, .. } => context.value(),
-            Self::Wrapped { .. } => None,
+            Self::Wrapped { context, .. } => context.value(),
         }
     }
 }
@@ -96,16 +250,16 @@ where
     C: crate::context::StringContext,
     K: Kind,
 {
-    pub fn get<Q>(&self, key: &Q) -> Option<&<C::Entry as Entry>::ValueBorrowed>
+    pub fn get_with_depth<Q>(
+        &self,
+        key: &Q,
+        depth: ContextDepth,
+    ) -> Option<&<C::Entry as Entry>::ValueBorrowed>
     where
         <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
         Q: Debug + Eq + Hash + ?Sized,
     {
-        match self {
-            Self::Simple { context, .. } => context.get(key),
-            Self::Layered { context, .. } => context.get(key),
-            Self::Wrapped { .. } => None,
-        }
+        self.find_in_context(depth, |context| context.get(key))
     }
 }
 
@@ -114,17 +268,13 @@ where
     C: crate::context::AnyContext,
     K: Kind,
 {
-    pub fn value_as<T, Q>(&self, key: &Q) -> Option<&T>
+    pub fn value_as_with_depth<T, Q>(&self, key: &Q, depth: ContextDepth) -> Option<&T>
     where
         <C::Entry as Entry>::KeyBorrowed: Borrow<Q>,
         Q: Debug + Eq + Hash + ?Sized,
         T: Any,
     {
-        match self {
-            Self::Simple { context, .. } => context.value_as::<T, _>(key),
-            Self::Layered { context, .. } => context.value_as::<T, _>(key),
-            Self::Wrapped { .. } => None,
-        }
+        self.find_in_context(depth, |context| context.value_as::<T, _>(key))
     }
 }
 
@@ -135,10 +285,18 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            Self::Simple { message, .. } => write!(f, "{message}"),
-            Self::Layered { message, .. } => write!(f, "{message}"),
-            Self::Wrapped { inner, .. } => write!(f, "{inner}"),
+            Self::Simple { message, .. } => write!(f, "{message}")?,
+            Self::Layered { message, .. } => write!(f, "{message}")?,
+            Self::Wrapped { inner, .. } => write!(f, "{inner}")?,
         }
+        if f.alternate() {
+            let mut source = self.source();
+            while let Some(error) = source {
+                write!(f, ": {error}")?;
+                source = error.source();
+            }
+        }
+        Ok(())
     }
 }
 
@@ -151,7 +309,7 @@ where
         match self {
             Self::Simple { .. } => None,
             Self::Layered { source, .. } => Some(source),
-            Self::Wrapped { .. } => None,
+            Self::Wrapped { inner, .. } => inner.source(),
         }
     }
 }
@@ -164,6 +322,7 @@ where
     kind: K,
     message: String,
     context: C,
+    attachments: Attachments,
     source: Option<AnyError<C, K>>,
 }
 
@@ -177,6 +336,7 @@ where
             kind: K::default(),
             message: String::new(),
             context: C::default(),
+            attachments: Attachments::default(),
             source: None,
         }
     }
@@ -196,20 +356,47 @@ where
         self
     }
 
-    pub fn build(self) -> ErrorData<C, K> {
+    pub fn error_code(mut self, code: ErrorCode) -> Self {
+        self.attachments.code = Some(code);
+        self
+    }
+
+    pub fn help<S: Into<String>>(mut self, help: S) -> Self {
+        self.attachments.helps.push(help.into());
+        self
+    }
+
+    pub fn note<S: Into<String>>(mut self, note: S) -> Self {
+        self.attachments.notes.push(note.into());
+        self
+    }
+
+    pub fn source_span(mut self, span: SourceSpan) -> Self {
+        self.attachments.source_spans.push(span);
+        self
+    }
+
+    pub fn build(mut self) -> ErrorData<C, K> {
         match self.source {
             Some(source) => ErrorData::Layered {
                 kind: self.kind,
                 message: self.message,
                 context: self.context,
+                attachments: self.attachments,
                 source,
             },
-            None => ErrorData::Simple {
-                kind: self.kind,
-                message: self.message,
-                backtrace: Backtrace::capture(),
-                context: self.context,
-            },
+            None => {
+                ambient::apply(&mut self.context);
+                ErrorData::Simple {
+                    kind: self.kind,
+                    message: self.message,
+                    backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
+                    context: self.context,
+                    attachments: self.attachments,
+                }
+            }
         }
     }
 }
@@ -247,7 +434,10 @@ mod tests {
                 kind: DefaultErrorKind::Unknown,
                 message: "simple".into(),
                 backtrace: Backtrace::capture(),
+                #[cfg(feature = "tracing")]
+                span_trace: SpanTrace::capture(),
                 context: LiteralKeyStringMapContext::new(),
+                attachments: Attachments::default(),
             };
             assert_eq!(data.message(), "simple");
             assert_eq!(data.to_string(), "simple");
@@ -257,11 +447,15 @@ mod tests {
                 kind: DefaultErrorKind::Unknown,
                 message: "layered".into(),
                 context: LiteralKeyStringMapContext::new(),
+                attachments: Attachments::default(),
                 source: AnyError::from(DefaultErrorData::Simple {
                     kind: DefaultErrorKind::Unknown,
                     message: "simple".into(),
                     backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
                     context: LiteralKeyStringMapContext::new(),
+                    attachments: Attachments::default(),
                 }),
             };
             assert_eq!(data.message(), "layered");
@@ -269,7 +463,11 @@ mod tests {
         }
         {
             let data = DefaultErrorData::Wrapped {
+                kind: DefaultErrorKind::Raw,
                 backtrace: Backtrace::capture(),
+                #[cfg(feature = "tracing")]
+                span_trace: SpanTrace::capture(),
+                context: LiteralKeyStringMapContext::new(),
                 inner: "wrapped".into(),
             };
             assert_eq!(data.message(), "wrapped");
@@ -277,6 +475,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn error_data_alternate_display_succeeds() {
+        #[derive(Debug)]
+        struct ForeignError(std::num::ParseIntError);
+
+        impl Display for ForeignError {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, "foreign")
+            }
+        }
+
+        impl Error for ForeignError {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let inner = "".parse::<u32>().unwrap_err();
+        let data = DefaultErrorData::Layered {
+            kind: DefaultErrorKind::Unknown,
+            message: "outer".into(),
+            context: LiteralKeyStringMapContext::new(),
+            attachments: Attachments::default(),
+            source: AnyError::from(DefaultErrorData::Layered {
+                kind: DefaultErrorKind::Unknown,
+                message: "middle".into(),
+                context: LiteralKeyStringMapContext::new(),
+                attachments: Attachments::default(),
+                source: AnyError::from(DefaultErrorData::Wrapped {
+                    kind: DefaultErrorKind::Raw,
+                    backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
+                    context: LiteralKeyStringMapContext::new(),
+                    inner: Box::new(ForeignError(inner.clone())),
+                }),
+            }),
+        };
+        assert_eq!(data.to_string(), "outer");
+        assert_eq!(
+            format!("{data:#}"),
+            format!("outer: middle: foreign: {inner}")
+        );
+    }
+
     #[test]
     fn error_data_context_succeeds() {
         {
@@ -284,7 +527,10 @@ mod tests {
                 kind: DefaultErrorKind::Unknown,
                 message: "simple".into(),
                 backtrace: Backtrace::capture(),
+                #[cfg(feature = "tracing")]
+                span_trace: SpanTrace::capture(),
                 context: LiteralKeyStringMapContext::from(vec![("key", "1")]),
+                attachments: Attachments::default(),
             };
 
             let mut iter = data.context(ContextDepth::All);
@@ -306,11 +552,15 @@ mod tests {
                 kind: DefaultErrorKind::Unknown,
                 message: "layered".into(),
                 context: LiteralKeyStringMapContext::from(vec![("key2", "2")]),
+                attachments: Attachments::default(),
                 source: AnyError::from(DefaultErrorData::Simple {
                     kind: DefaultErrorKind::Unknown,
                     message: "simple".into(),
                     backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
                     context: LiteralKeyStringMapContext::from(vec![("key1", "1")]),
+                    attachments: Attachments::default(),
                 }),
             };
 
@@ -334,7 +584,11 @@ mod tests {
         }
         {
             let data = DefaultErrorData::Wrapped {
+                kind: DefaultErrorKind::Raw,
                 backtrace: Backtrace::capture(),
+                #[cfg(feature = "tracing")]
+                span_trace: SpanTrace::capture(),
+                context: LiteralKeyStringMapContext::new(),
                 inner: "wrapped".into(),
             };
 
@@ -346,6 +600,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn error_data_context_succeeds_with_selected_depth() {
+        let data = DefaultErrorData::Layered {
+            kind: DefaultErrorKind::Unknown,
+            message: "outer".into(),
+            context: LiteralKeyStringMapContext::from(vec![("key0", "0")]),
+            attachments: Attachments::default(),
+            source: AnyError::from(DefaultErrorData::Layered {
+                kind: DefaultErrorKind::Unknown,
+                message: "middle".into(),
+                context: LiteralKeyStringMapContext::from(vec![("key1", "1")]),
+                attachments: Attachments::default(),
+                source: AnyError::from(DefaultErrorData::Simple {
+                    kind: DefaultErrorKind::Unknown,
+                    message: "inner".into(),
+                    backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
+                    context: LiteralKeyStringMapContext::from(vec![("key2", "2")]),
+                    attachments: Attachments::default(),
+                }),
+            }),
+        };
+        let keys = |depth| {
+            data.context(depth)
+                .map(|entry| entry.key())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(data.deepest(), 2);
+        assert_eq!(keys(ContextDepth::All), ["key0", "key1", "key2"]);
+        assert_eq!(keys(ContextDepth::Shallowest), ["key0"]);
+        assert_eq!(keys(ContextDepth::Deepest), ["key2"]);
+        assert_eq!(keys(ContextDepth::Nth(1)), ["key1"]);
+        assert_eq!(keys(ContextDepth::Nth(3)), Vec::<&str>::new());
+        assert_eq!(keys(ContextDepth::UpTo(1)), ["key0", "key1"]);
+        assert_eq!(
+            keys(ContextDepth::Range { start: 1, end: 3 }),
+            ["key1", "key2"]
+        );
+        assert_eq!(data.get_with_depth("key2", ContextDepth::UpTo(1)), None);
+        assert_eq!(
+            data.get_with_depth("key2", ContextDepth::Deepest),
+            Some("2")
+        );
+    }
+
     #[test]
     fn error_data_builder_build() {
         {
@@ -365,7 +666,10 @@ mod tests {
                     kind: DefaultErrorKind::Unknown,
                     message: "simple".into(),
                     backtrace: Backtrace::capture(),
+                    #[cfg(feature = "tracing")]
+                    span_trace: SpanTrace::capture(),
                     context: LiteralKeyStringMapContext::from(vec![("key1", "1")]),
+                    attachments: Attachments::default(),
                 }))
                 .build();
             assert_eq!(data.kind(), DefaultErrorKind::default());
@@ -0,0 +1,209 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use tracing::Level;
+
+use crate::context::{AbstractContext, Entry, MergePolicy, Value};
+use crate::core::data::ErrorData;
+use crate::core::{AnyError, ContextDepth};
+use crate::kind::Kind;
+
+macro_rules! record_event {
+    ($level:expr, $error:expr) => {{
+        let error = $error;
+        tracing::event!(
+            $level,
+            error.kind = %error.kind(),
+            error.message = %error,
+            error.chain = ?Chain(&error.0),
+            error.kinds = ?Kinds(&error.0),
+            error.context = %context_json(error),
+            "{error:#}"
+        )
+    }};
+}
+
+impl<C, K> AnyError<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    /// Emits this error as an event through the [`tracing`] crate with the
+    /// given `level`, which is only available with the `tracing` feature
+    /// enabled.
+    ///
+    /// The event's message is the whole chain of error messages in one line,
+    /// and the following structured fields are attached:
+    ///
+    /// - `error.kind`: the kind of the outermost error
+    /// - `error.message`: the message of the outermost error
+    /// - `error.chain`: the list of the messages of all layers
+    /// - `error.kinds`: the list of the kinds of all layers
+    /// - `error.context`: the JSON object of the context attached to all
+    ///   layers, which corresponds to [`ContextDepth::All`]. If multiple
+    ///   entries share the same key, the one from the outermost layer is kept,
+    ///   and each value is converted by [`Entry::structured_value()`]
+    ///
+    /// Since [`tracing`] only accepts field names known at compile time, the
+    /// context is recorded as one JSON field rather than a field per entry.
+    ///
+    /// [`tracing`]: https://docs.rs/tracing
+    pub fn record(&self, level: Level) {
+        match level {
+            Level::ERROR => record_event!(Level::ERROR, self),
+            Level::WARN => record_event!(Level::WARN, self),
+            Level::INFO => record_event!(Level::INFO, self),
+            Level::DEBUG => record_event!(Level::DEBUG, self),
+            Level::TRACE => record_event!(Level::TRACE, self),
+        }
+    }
+}
+
+struct Chain<'a, C, K>(&'a ErrorData<C, K>)
+where
+    C: AbstractContext,
+    K: Kind;
+
+impl<C, K> Debug for Chain<'_, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list()
+            .entries(self.0.layers().map(ErrorData::message))
+            .finish()
+    }
+}
+
+struct Kinds<'a, C, K>(&'a ErrorData<C, K>)
+where
+    C: AbstractContext,
+    K: Kind;
+
+impl<C, K> Debug for Kinds<'_, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_list()
+            .entries(self.0.layer_kinds().map(|kind| kind.to_string()))
+            .finish()
+    }
+}
+
+fn context_json<C, K>(error: &AnyError<C, K>) -> String
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    let entries = MergePolicy::OutermostWins
+        .merge(error.context(ContextDepth::All))
+        .into_iter()
+        .map(|entry| (entry.key().to_string(), entry.structured_value()));
+    Value::Map(entries.collect()).to_json()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+    use tracing_subscriber::{Layer, Registry};
+
+    use crate::context::{LiteralKeyValueMapContext, StringKeyStringMapContext};
+    use crate::kind::DefaultErrorKind;
+    use crate::{Intermediate, Overlay};
+
+    use super::*;
+
+    type TestError = AnyError<StringKeyStringMapContext, DefaultErrorKind>;
+
+    type EventRecord = (Level, Vec<(String, String)>);
+
+    #[derive(Clone, Default)]
+    struct TestLayer(Arc<Mutex<Vec<EventRecord>>>);
+
+    impl<S: Subscriber> Layer<S> for TestLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+            struct Visitor(Vec<(String, String)>);
+
+            impl Visit for Visitor {
+                fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                    self.0.push((field.name().into(), format!("{value:?}")));
+                }
+            }
+
+            let mut visitor = Visitor(Vec::new());
+            event.record(&mut visitor);
+            let record = (*event.metadata().level(), visitor.0);
+            self.0.lock().unwrap().push(record);
+        }
+    }
+
+    #[test]
+    fn any_error_record_succeeds() {
+        let layer = TestLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            TestError::builder()
+                .message("inner")
+                .kind(DefaultErrorKind::ValueValidation)
+                .context("key1", 1)
+                .context("key2", "shadowed")
+                .build()
+                .overlay("outer")
+                .context("key2", "value")
+                .build()
+                .record(Level::WARN);
+        });
+
+        let records = layer.0.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let (level, fields) = &records[0];
+        assert_eq!(*level, Level::WARN);
+        assert_eq!(
+            *fields,
+            vec![
+                ("message".into(), "outer: inner".into()),
+                ("error.kind".into(), "Unknown".into()),
+                ("error.message".into(), "outer".into()),
+                ("error.chain".into(), r#"["outer", "inner"]"#.into()),
+                (
+                    "error.kinds".into(),
+                    r#"["Unknown", "ValueValidation"]"#.into()
+                ),
+                (
+                    "error.context".into(),
+                    r#"{"key1":"1","key2":"\"value\""}"#.into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn any_error_record_succeeds_with_value_context() {
+        type ValueError = AnyError<LiteralKeyValueMapContext, DefaultErrorKind>;
+
+        let layer = TestLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            ValueError::builder()
+                .message("error")
+                .context("id", 42)
+                .context("tags", vec!["a", "b"])
+                .build()
+                .record(Level::ERROR);
+        });
+
+        let records = layer.0.lock().unwrap();
+        let (_, fields) = &records[0];
+        let context = fields
+            .iter()
+            .find(|(name, _)| name == "error.context")
+            .map(|(_, value)| value.as_str());
+        assert_eq!(context, Some(r#"{"id":42,"tags":["a","b"]}"#));
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::context::Value;
+
+/// A labeled byte range of some named source text, such as a configuration
+/// file or a script, which tells where exactly an error occurs.
+///
+/// Source spans are attached to errors through
+/// [`AnyErrorBuilder::source_span()`], and pretty reports render the
+/// surrounding source lines with the range underlined. The [`Display`]
+/// implementation renders the same snippet on its own.
+///
+/// The range is measured in bytes. Offsets beyond the end of the text are
+/// clamped, and offsets inside a multi-byte character are moved back to the
+/// start of that character.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::diagnostic::SourceSpan;
+/// let text = "[server]\nport = \"eighty\"\nhost = \"localhost\"\n";
+/// let span = SourceSpan::new("config.toml", text, 16..24).with_label("expected an integer");
+/// assert_eq!(span.location(), (2, 8));
+/// assert_eq!(
+///     span.to_string(),
+///     concat!(
+///         " --> config.toml:2:8\n",
+///         "  |\n",
+///         "1 | [server]\n",
+///         "2 | port = \"eighty\"\n",
+///         "  |        ^^^^^^^^ expected an integer\n",
+///         "3 | host = \"localhost\"\n",
+///     )
+/// );
+/// ```
+///
+/// [`AnyErrorBuilder::source_span()`]: crate::core::AnyErrorBuilder::source_span
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+    source_name: String,
+    text: Arc<str>,
+    range: Range<usize>,
+    label: Option<String>,
+}
+
+impl SourceSpan {
+    /// Creates a [`SourceSpan`] pointing at `range` of `text`, which is read
+    /// from the source named `source_name`, typically a file path.
+    pub fn new<N, T>(source_name: N, text: T, range: Range<usize>) -> Self
+    where
+        N: Into<String>,
+        T: Into<Arc<str>>,
+    {
+        Self {
+            source_name: source_name.into(),
+            text: text.into(),
+            range,
+            label: None,
+        }
+    }
+
+    /// Sets the label printed next to the underlined range.
+    pub fn with_label<S: Into<String>>(self, label: S) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Returns the name of the source.
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// Returns the whole source text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte range pointed at, as given on creation.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the label of the range.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns the 1-based line and column, counted in characters, where the
+    /// range starts.
+    pub fn location(&self) -> (usize, usize) {
+        let start = self.clamp(self.range.start);
+        let line_start = self.text[..start].rfind('\n').map_or(0, |index| index + 1);
+        let line = self.text[..start].matches('\n').count() + 1;
+        let column = self.text[line_start..start].chars().count() + 1;
+        (line, column)
+    }
+
+    /// Collects the span into a [`Value::Map`] with the keys `source_name`,
+    /// `start`, `end`, `line`, `column` and `label`.
+    pub fn to_value(&self) -> Value {
+        let (line, column) = self.location();
+        let mut map = BTreeMap::new();
+        map.insert("source_name".into(), self.source_name.as_str().into());
+        map.insert("start".into(), self.range.start.into());
+        map.insert("end".into(), self.range.end.into());
+        map.insert("line".into(), line.into());
+        map.insert("column".into(), column.into());
+        map.insert("label".into(), self.label.as_deref().into());
+        Value::Map(map)
+    }
+
+    /// Returns the largest offset which is not beyond `offset` and lies on a
+    /// character boundary of the text.
+    fn clamp(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.text.len());
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+}
+
+impl Display for SourceSpan {
+    /// Renders the lines covered by the range along with one line before and
+    /// after it, and underlines the range with carets.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let start = self.clamp(self.range.start);
+        let end = self.clamp(self.range.end).max(start);
+
+        let mut offset = 0;
+        let lines = self
+            .text
+            .split('\n')
+            .map(|line| {
+                let line_start = offset;
+                offset += line.len() + 1;
+                (line_start, line.strip_suffix('\r').unwrap_or(line))
+            })
+            .collect::<Vec<_>>();
+        let line_of = |offset: usize| {
+            lines
+                .iter()
+                .rposition(|(line_start, _)| *line_start <= offset)
+                .unwrap_or(0)
+        };
+        let start_line = line_of(start);
+        let end_line = if end > start {
+            line_of(end - 1)
+        } else {
+            start_line
+        };
+        // A trailing newline ends the last line rather than starting a new
+        // one, so the empty segment after it is only shown if it's pointed at.
+        let line_count = lines.len() - usize::from(self.text.ends_with('\n'));
+        let first = start_line.saturating_sub(1);
+        let last = (end_line + 1)
+            .min(line_count.saturating_sub(1))
+            .max(end_line);
+        let width = (last + 1).to_string().len();
+
+        let (line, column) = self.location();
+        writeln!(f, "{:width$}--> {}:{line}:{column}", "", self.source_name)?;
+        writeln!(f, "{:width$} |", "")?;
+        for (index, (line_start, line)) in lines.iter().enumerate().take(last + 1).skip(first) {
+            if line.is_empty() {
+                writeln!(f, "{:>width$} |", index + 1)?;
+            } else {
+                writeln!(f, "{:>width$} | {line}", index + 1)?;
+            }
+            if index < start_line || index > end_line {
+                continue;
+            }
+            let from = start.max(*line_start).min(line_start + line.len()) - line_start;
+            let to = end.min(line_start + line.len()).max(from + line_start) - line_start;
+            let indent = line[..from].chars().count();
+            let carets = line[from..to].chars().count().max(1);
+            write!(f, "{:width$} | {:indent$}{}", "", "", "^".repeat(carets))?;
+            match &self.label {
+                Some(label) if index == end_line => writeln!(f, " {label}")?,
+                _ => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "[server]\nport = \"eighty\"\nhost = \"localhost\"\n";
+
+    #[test]
+    fn source_span_location_succeeds() {
+        assert_eq!(SourceSpan::new("a", TEXT, 0..1).location(), (1, 1));
+        assert_eq!(SourceSpan::new("a", TEXT, 16..24).location(), (2, 8));
+        assert_eq!(SourceSpan::new("a", TEXT, 9..9).location(), (2, 1));
+        assert_eq!(SourceSpan::new("a", TEXT, 100..200).location(), (4, 1));
+        assert_eq!(SourceSpan::new("a", "ä = 1", 1..2).location(), (1, 1));
+        assert_eq!(SourceSpan::new("a", "ä = 1", 3..4).location(), (1, 3));
+    }
+
+    #[test]
+    fn source_span_display_succeeds_with_multiple_lines() {
+        let span = SourceSpan::new("config.toml", TEXT, 9..44).with_label("invalid entries");
+        assert_eq!(
+            span.to_string(),
+            concat!(
+                " --> config.toml:2:1\n",
+                "  |\n",
+                "1 | [server]\n",
+                "2 | port = \"eighty\"\n",
+                "  | ^^^^^^^^^^^^^^^\n",
+                "3 | host = \"localhost\"\n",
+                "  | ^^^^^^^^^^^^^^^^^^ invalid entries\n",
+            )
+        );
+    }
+
+    #[test]
+    fn source_span_display_succeeds_with_trailing_newline() {
+        let span = SourceSpan::new("input", "a = 1\nb = 2\n", 10..11);
+        assert_eq!(
+            span.to_string(),
+            " --> input:2:5\n  |\n1 | a = 1\n2 | b = 2\n  |     ^\n"
+        );
+
+        let span = SourceSpan::new("input", "a = 1\n", 6..6).with_label("expected `b`");
+        assert_eq!(
+            span.to_string(),
+            " --> input:2:1\n  |\n1 | a = 1\n2 |\n  | ^ expected `b`\n"
+        );
+    }
+
+    #[test]
+    fn source_span_display_succeeds_with_edge_cases() {
+        let span = SourceSpan::new("input", "let x = ;", 8..8);
+        assert_eq!(
+            span.to_string(),
+            " --> input:1:9\n  |\n1 | let x = ;\n  |         ^\n"
+        );
+
+        let span = SourceSpan::new("input", "日本 = 語", 9..12).with_label("here");
+        assert_eq!(
+            span.to_string(),
+            " --> input:1:6\n  |\n1 | 日本 = 語\n  |      ^ here\n"
+        );
+
+        let span = SourceSpan::new("input", "a\r\nb", 3..100);
+        assert_eq!(
+            span.to_string(),
+            " --> input:2:1\n  |\n1 | a\n2 | b\n  | ^\n"
+        );
+    }
+
+    #[test]
+    fn source_span_to_value_succeeds() {
+        let span = SourceSpan::new("config.toml", TEXT, 16..24).with_label("expected an integer");
+        assert_eq!(
+            span.to_value().to_string(),
+            concat!(
+                r#"{"column":8,"end":24,"label":"expected an integer","line":2,"#,
+                r#""source_name":"config.toml","start":16}"#
+            )
+        );
+        let span = SourceSpan::new("config.toml", TEXT, 0..1);
+        assert!(span.to_value().to_string().contains(r#""label":null"#));
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use ::http::StatusCode;
+
+use crate::context::{AbstractContext, Entry, Value};
+use crate::core::ContextDepth;
+use crate::kind::{DefaultErrorKind, Kind, NoErrorKind};
+use crate::AnyError;
+
+/// An error kind which can be mapped to an HTTP status code.
+pub trait HttpKind: Kind {
+    /// Returns the HTTP status code of this kind of error, which is
+    /// `500 Internal Server Error` by default.
+    fn status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+impl HttpKind for DefaultErrorKind {
+    /// Maps the kinds as follows:
+    ///
+    /// - [`DefaultErrorKind::ValueValidation`]: `400 Bad Request`
+    /// - [`DefaultErrorKind::EntityAbsence`]: `404 Not Found`
+    /// - [`DefaultErrorKind::RuleViolation`]: `422 Unprocessable Entity`
+    /// - [`DefaultErrorKind::InfrastructureFailure`]: `503 Service Unavailable`
+    /// - Others: `500 Internal Server Error`
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ValueValidation => StatusCode::BAD_REQUEST,
+            Self::EntityAbsence => StatusCode::NOT_FOUND,
+            Self::RuleViolation => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InfrastructureFailure => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Raw | Self::Unknown => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl HttpKind for NoErrorKind {}
+
+impl<C, K> AnyError<C, K>
+where
+    C: AbstractContext,
+    K: HttpKind,
+{
+    /// Returns the HTTP status code of this error.
+    ///
+    /// The kinds along the error chain are checked from the outermost layer
+    /// to the innermost one, and the first kind which is neither
+    /// [`Kind::RAW_KIND`] nor [`Kind::UNKNOWN_KIND`] decides the status code.
+    /// `500 Internal Server Error` is returned if no such kind is found.
+    pub fn status_code(&self) -> StatusCode {
+        self.specified_kind()
+            .map_or(StatusCode::INTERNAL_SERVER_ERROR, |kind| kind.status_code())
+    }
+}
+
+/// The problem details of an HTTP API error defined by [RFC 7807].
+///
+/// A [`Problem`] is typically made from an [`AnyError`] with
+/// [`Problem::from_error()`], and its [`Display`] implementation renders the
+/// JSON body of the response.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::LiteralKeyDisplayStringMapContext;
+/// # use anyerr::http::Problem;
+/// # use anyerr::kind::DefaultErrorKind;
+/// type AnyError = AnyErrorTemplate<LiteralKeyDisplayStringMapContext, DefaultErrorKind>;
+/// let err = AnyError::builder()
+///     .kind(DefaultErrorKind::EntityAbsence)
+///     .message("user 42 was not found")
+///     .context("user-id", 42)
+///     .context("table", "users")
+///     .build();
+/// let problem = Problem::from_error(&err).context(&err, &["user-id"]);
+/// assert_eq!(problem.status(), 404);
+/// assert_eq!(
+///     problem.to_json(),
+///     r#"{"detail":"user 42 was not found","status":404,"title":"Not Found","user-id":"42"}"#
+/// );
+/// ```
+///
+/// [RFC 7807]: https://www.rfc-editor.org/rfc/rfc7807
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    status: StatusCode,
+    type_uri: Option<String>,
+    title: String,
+    detail: Option<String>,
+    instance: Option<String>,
+    extensions: BTreeMap<String, Value>,
+}
+
+impl Problem {
+    /// The media type of the problem details in JSON.
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Creates a [`Problem`] of the given status code, whose title is the
+    /// canonical reason of the status code.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            type_uri: None,
+            title: status.canonical_reason().unwrap_or_default().into(),
+            detail: None,
+            instance: None,
+            extensions: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a [`Problem`] from `error`, whose status code is given by
+    /// [`AnyError::status_code()`].
+    ///
+    /// The detail is the message of the layer whose kind decides the status
+    /// code, and is set only if that kind is [user-facing]. Messages of other
+    /// layers are never used, so that internal failures are not exposed to
+    /// clients.
+    ///
+    /// [user-facing]: crate::kind::Kind::is_user_facing
+    pub fn from_error<C, K>(error: &AnyError<C, K>) -> Self
+    where
+        C: AbstractContext,
+        K: HttpKind,
+    {
+        let problem = Self::new(error.status_code());
+        match error.specified_layer() {
+            Some(layer) if layer.kind().is_user_facing() => problem.detail(layer.message()),
+            _ => problem,
+        }
+    }
+
+    /// Sets the URI which identifies the problem type.
+    pub fn type_uri<S: Into<String>>(self, type_uri: S) -> Self {
+        Self {
+            type_uri: Some(type_uri.into()),
+            ..self
+        }
+    }
+
+    /// Sets the short summary of the problem type.
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        Self {
+            title: title.into(),
+            ..self
+        }
+    }
+
+    /// Sets the explanation specific to this occurrence of the problem.
+    pub fn detail<S: Into<String>>(self, detail: S) -> Self {
+        Self {
+            detail: Some(detail.into()),
+            ..self
+        }
+    }
+
+    /// Sets the URI which identifies this occurrence of the problem.
+    pub fn instance<S: Into<String>>(self, instance: S) -> Self {
+        Self {
+            instance: Some(instance.into()),
+            ..self
+        }
+    }
+
+    /// Adds an extension member. Members defined by RFC 7807 always take
+    /// precedence over extensions of the same name.
+    pub fn extension<Q: Into<String>, R: Into<Value>>(mut self, key: Q, value: R) -> Self {
+        self.extensions.insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds the context entries of `error` whose keys are in `keys` as
+    /// extension members. If multiple entries share the same key, the one
+    /// from the outermost layer is used.
+    ///
+    /// Each value is converted by [`Entry::structured_value()`], so strings
+    /// are added as they are stored. Note that a context which converts values
+    /// with [`DebugConverter`] stores strings quoted.
+    ///
+    /// [`DebugConverter`]: crate::converter::DebugConverter
+    pub fn context<C, K>(mut self, error: &AnyError<C, K>, keys: &[&str]) -> Self
+    where
+        C: AbstractContext,
+        K: Kind,
+    {
+        for entry in error.context(ContextDepth::All) {
+            let key = entry.key().to_string();
+            if keys.contains(&key.as_str()) && !self.extensions.contains_key(&key) {
+                self.extensions.insert(key, entry.structured_value());
+            }
+        }
+        self
+    }
+
+    /// Returns the status code.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Collects all members into a [`Value::Map`].
+    pub fn to_value(&self) -> Value {
+        let mut map = self.extensions.clone();
+        let members = [
+            ("type", self.type_uri.clone().map(Value::from)),
+            ("title", Some(self.title.clone().into())),
+            ("status", Some(self.status.as_u16().into())),
+            ("detail", self.detail.clone().map(Value::from)),
+            ("instance", self.instance.clone().map(Value::from)),
+        ];
+        for (key, value) in members {
+            if let Some(value) = value {
+                map.insert(key.into(), value);
+            }
+        }
+        Value::Map(map)
+    }
+
+    /// Returns the JSON representation of the problem details, which is the
+    /// same as its [`Display`] representation.
+    pub fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::{LiteralKeyDisplayStringMapContext, LiteralKeyValueMapContext};
+    use crate::{Intermediate, Overlay};
+
+    use super::*;
+
+    type TestError = AnyError<LiteralKeyValueMapContext, DefaultErrorKind>;
+
+    #[test]
+    fn any_error_status_code_succeeds() {
+        let err = TestError::wrap(std::fmt::Error);
+        assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let err = TestError::quick("absent", DefaultErrorKind::EntityAbsence)
+            .overlay("outer")
+            .build();
+        assert_eq!(err.status_code(), StatusCode::NOT_FOUND);
+
+        let err = err
+            .overlay(("invalid", DefaultErrorKind::ValueValidation))
+            .build();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+        let err = TestError::quick("conflict", DefaultErrorKind::RuleViolation);
+        assert_eq!(err.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let err = TestError::quick("down", DefaultErrorKind::InfrastructureFailure);
+        assert_eq!(err.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn problem_from_error_succeeds() {
+        let err = TestError::builder()
+            .kind(DefaultErrorKind::ValueValidation)
+            .message("invalid age")
+            .context("age", -1)
+            .context("field", "age")
+            .build()
+            .overlay("could not create the user")
+            .context("request-id", 42)
+            .context("age", "outer")
+            .build();
+        let problem = Problem::from_error(&err)
+            .type_uri("https://example.com/problems/invalid-input")
+            .instance("/users")
+            .context(&err, &["age", "request-id", "absent"]);
+        assert_eq!(problem.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            problem.to_json(),
+            concat!(
+                r#"{"age":"outer","detail":"invalid age","#,
+                r#""instance":"/users","request-id":42,"status":400,"#,
+                r#""title":"Bad Request","#,
+                r#""type":"https://example.com/problems/invalid-input"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn problem_from_error_hides_internal_outer_messages() {
+        let err = TestError::quick("user 42 was not found", DefaultErrorKind::EntityAbsence)
+            .overlay("query `SELECT * FROM users` on 10.0.0.1 returned nothing")
+            .build();
+        let problem = Problem::from_error(&err);
+        assert_eq!(problem.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            problem.to_json(),
+            r#"{"detail":"user 42 was not found","status":404,"title":"Not Found"}"#
+        );
+
+        let err = TestError::quick("down", DefaultErrorKind::InfrastructureFailure)
+            .overlay(("invalid user", DefaultErrorKind::Unknown))
+            .build()
+            .overlay("connection to 10.0.0.1 refused")
+            .build();
+        assert_eq!(
+            Problem::from_error(&err).to_json(),
+            r#"{"status":503,"title":"Service Unavailable"}"#
+        );
+    }
+
+    #[test]
+    fn problem_from_error_hides_internal_messages() {
+        let err = AnyError::<LiteralKeyDisplayStringMapContext, DefaultErrorKind>::builder()
+            .kind(DefaultErrorKind::InfrastructureFailure)
+            .message("connection to 10.0.0.1 refused")
+            .context("host", "10.0.0.1")
+            .build();
+        let problem = Problem::from_error(&err)
+            .extension("status", 200)
+            .extension("retry", true)
+            .context(&err, &["host"]);
+        assert_eq!(
+            problem.to_string(),
+            r#"{"host":"10.0.0.1","retry":true,"status":503,"title":"Service Unavailable"}"#
+        );
+    }
+}
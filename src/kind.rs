@@ -1,5 +1,13 @@
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::hash::Hash;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+use std::num::{ParseFloatError, ParseIntError, TryFromIntError};
+use std::str::{ParseBoolError, Utf8Error};
+use std::string::FromUtf8Error;
+use std::sync::mpsc::RecvTimeoutError;
+
+use crate::code::ErrorCode;
 
 /// The error kind used by [`AnyError`].
 ///
@@ -33,6 +41,107 @@ pub trait Kind:
     fn is_unknown(&self) -> bool {
         *self == Self::UNKNOWN_KIND
     }
+
+    /// Returns true if the operation which failed with this kind of error may
+    /// succeed when it's retried. Errors are not retryable by default.
+    fn is_retryable(&self) -> bool {
+        false
+    }
+
+    /// Returns how severe this kind of error is, which is typically used to
+    /// choose the logging level. Errors are of [`Severity::Error`] by default.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Returns true if the message of this kind of error can be shown to end
+    /// users. Errors are not user-facing by default.
+    fn is_user_facing(&self) -> bool {
+        false
+    }
+
+    /// Returns the stable code published for this kind of error, or `None`
+    /// if there's no such code, which is the default. Codes attached to
+    /// individual errors with [`AnyErrorBuilder::error_code()`] take precedence.
+    ///
+    /// [`AnyErrorBuilder::error_code()`]: crate::core::AnyErrorBuilder::error_code
+    fn error_code(&self) -> Option<ErrorCode> {
+        None
+    }
+
+    /// Returns the kind of a foreign error wrapped by [`AnyError::wrap()`], or
+    /// `None` if it can't be classified, in which case [`Kind::RAW_KIND`] is
+    /// used. Nothing is classified by default.
+    ///
+    /// Implementations typically try to downcast `error` to the error types
+    /// they know about.
+    ///
+    /// [`AnyError::wrap()`]: crate::core::AnyError::wrap
+    fn classify(error: &(dyn Error + 'static)) -> Option<Self> {
+        let _ = error;
+        None
+    }
+
+    /// Returns the parent of this kind in a kind hierarchy, or `None` if it's
+    /// a top-level kind. Kinds have no parent by default.
+    ///
+    /// The hierarchy must not contain cycles.
+    fn parent(&self) -> Option<Self> {
+        None
+    }
+
+    /// Returns true if this kind is `ancestor` itself or one of its
+    /// descendants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::kind::{Kind, PathKind};
+    /// let kind = PathKind::new("storage.io.timeout");
+    /// assert!(kind.is_a(PathKind::new("storage")));
+    /// assert!(kind.is_a(PathKind::new("storage.io")));
+    /// assert!(!kind.is_a(PathKind::new("storage.io.timeout.read")));
+    /// assert!(!kind.is_a(PathKind::new("network")));
+    /// ```
+    fn is_a(&self, ancestor: Self) -> bool {
+        let mut kind = Some(*self);
+        while let Some(current) = kind {
+            if current == ancestor {
+                return true;
+            }
+            kind = current.parent();
+        }
+        false
+    }
+}
+
+/// The severity of an error kind, from the least severe to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Severity {
+    /// The error is only interesting when debugging.
+    Debug,
+    /// The error is expected and doesn't need any attention.
+    Info,
+    /// The error should be noticed but doesn't break anything.
+    Warning,
+    /// The error breaks the current operation.
+    #[default]
+    Error,
+    /// The error breaks the whole application or requires immediate action.
+    Critical,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let value = match self {
+            Self::Debug => "Debug",
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+            Self::Critical => "Critical",
+        };
+        write!(f, "{value}")
+    }
 }
 
 /// A predefined error kind based on the crate author's development experience.
@@ -66,6 +175,76 @@ impl Kind for DefaultErrorKind {
     const RAW_KIND: Self = DefaultErrorKind::Raw;
 
     const UNKNOWN_KIND: Self = DefaultErrorKind::Unknown;
+
+    /// Returns true only for [`DefaultErrorKind::InfrastructureFailure`],
+    /// since such failures are usually transient.
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::InfrastructureFailure)
+    }
+
+    /// Returns [`Severity::Warning`] for errors caused by the input, and
+    /// [`Severity::Error`] for others.
+    fn severity(&self) -> Severity {
+        match self {
+            Self::ValueValidation | Self::RuleViolation | Self::EntityAbsence => Severity::Warning,
+            Self::InfrastructureFailure | Self::Raw | Self::Unknown => Severity::Error,
+        }
+    }
+
+    /// Returns true for errors caused by the input, whose messages are meant
+    /// to tell end users what went wrong.
+    fn is_user_facing(&self) -> bool {
+        matches!(
+            self,
+            Self::ValueValidation | Self::RuleViolation | Self::EntityAbsence
+        )
+    }
+
+    /// Classifies some common errors from the standard library:
+    ///
+    /// - Parsing and conversion errors, such as [`ParseIntError`], are
+    ///   classified as [`DefaultErrorKind::ValueValidation`].
+    /// - [`io::Error`]s are classified by their [`io::ErrorKind`], where
+    ///   `NotFound` is [`DefaultErrorKind::EntityAbsence`], `AlreadyExists` is
+    ///   [`DefaultErrorKind::RuleViolation`], `InvalidInput` and
+    ///   `InvalidData` are [`DefaultErrorKind::ValueValidation`], and timeouts
+    ///   and connection failures are
+    ///   [`DefaultErrorKind::InfrastructureFailure`].
+    /// - Timeouts of [`RecvTimeoutError`] are classified as
+    ///   [`DefaultErrorKind::InfrastructureFailure`].
+    ///
+    /// [`io::Error`]: std::io::Error
+    /// [`io::ErrorKind`]: std::io::ErrorKind
+    fn classify(error: &(dyn Error + 'static)) -> Option<Self> {
+        if error.is::<ParseIntError>()
+            || error.is::<ParseFloatError>()
+            || error.is::<ParseBoolError>()
+            || error.is::<TryFromIntError>()
+            || error.is::<Utf8Error>()
+            || error.is::<FromUtf8Error>()
+        {
+            return Some(Self::ValueValidation);
+        }
+        if let Some(error) = error.downcast_ref::<IoError>() {
+            return match error.kind() {
+                IoErrorKind::NotFound => Some(Self::EntityAbsence),
+                IoErrorKind::AlreadyExists => Some(Self::RuleViolation),
+                IoErrorKind::InvalidInput | IoErrorKind::InvalidData => Some(Self::ValueValidation),
+                IoErrorKind::TimedOut
+                | IoErrorKind::ConnectionRefused
+                | IoErrorKind::ConnectionReset
+                | IoErrorKind::ConnectionAborted
+                | IoErrorKind::NotConnected
+                | IoErrorKind::BrokenPipe
+                | IoErrorKind::Interrupted => Some(Self::InfrastructureFailure),
+                _ => None,
+            };
+        }
+        if let Some(RecvTimeoutError::Timeout) = error.downcast_ref::<RecvTimeoutError>() {
+            return Some(Self::InfrastructureFailure);
+        }
+        None
+    }
 }
 
 /// A predefined error kind that is used when no error kind is needed.
@@ -86,3 +265,108 @@ impl Kind for NoErrorKind {
 
     const UNKNOWN_KIND: Self = Self::Anything;
 }
+
+/// A predefined error kind represented as a dot-separated path, such as
+/// `"storage.io.timeout"`, for those who don't want to maintain a large enum.
+///
+/// The parent of a [`PathKind`] is the path without its last segment, so
+/// `"storage.io.timeout"` is a `"storage.io"` and a `"storage"` according to
+/// [`Kind::is_a()`]. The paths `"raw"` and `"unknown"` are reserved for
+/// [`Kind::RAW_KIND`] and [`Kind::UNKNOWN_KIND`] respectively.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::LiteralKeyStringMapContext;
+/// # use anyerr::kind::{Kind, PathKind};
+/// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, PathKind>;
+///
+/// const STORAGE: PathKind = PathKind::new("storage");
+/// const STORAGE_TIMEOUT: PathKind = PathKind::new("storage.io.timeout");
+///
+/// let err = AnyError::quick("the disk is too slow", STORAGE_TIMEOUT);
+/// assert!(err.kind().is_a(STORAGE));
+/// assert_eq!(err.kind().to_string(), "storage.io.timeout");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PathKind(&'static str);
+
+impl PathKind {
+    /// Creates a [`PathKind`] from a dot-separated path.
+    pub const fn new(path: &'static str) -> Self {
+        Self(path)
+    }
+
+    /// Returns the whole path.
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Returns the last segment of the path.
+    pub fn name(&self) -> &'static str {
+        self.0.rsplit('.').next().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the segments of the path.
+    pub fn segments(&self) -> impl Iterator<Item = &'static str> {
+        self.0.split('.')
+    }
+}
+
+impl Default for PathKind {
+    fn default() -> Self {
+        Self::UNKNOWN_KIND
+    }
+}
+
+impl Display for PathKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&'static str> for PathKind {
+    fn from(path: &'static str) -> Self {
+        Self::new(path)
+    }
+}
+
+impl Kind for PathKind {
+    const RAW_KIND: Self = Self::new("raw");
+
+    const UNKNOWN_KIND: Self = Self::new("unknown");
+
+    fn parent(&self) -> Option<Self> {
+        self.0.rsplit_once('.').map(|(parent, _)| Self::new(parent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_kind_hierarchy_succeeds() {
+        let kind = PathKind::new("storage.io.timeout");
+        assert_eq!(kind.parent(), Some(PathKind::new("storage.io")));
+        assert_eq!(kind.name(), "timeout");
+        assert_eq!(
+            kind.segments().collect::<Vec<_>>(),
+            ["storage", "io", "timeout"]
+        );
+        assert_eq!(PathKind::new("storage").parent(), None);
+
+        assert!(kind.is_a(kind));
+        assert!(kind.is_a(PathKind::new("storage")));
+        assert!(!kind.is_a(PathKind::new("stor")));
+        assert!(!PathKind::new("storage").is_a(kind));
+        assert!(PathKind::default().is_unknown());
+    }
+
+    #[test]
+    fn default_error_kind_is_a_succeeds() {
+        assert!(DefaultErrorKind::RuleViolation.is_a(DefaultErrorKind::RuleViolation));
+        assert!(!DefaultErrorKind::RuleViolation.is_a(DefaultErrorKind::ValueValidation));
+    }
+}
@@ -43,7 +43,11 @@
 //! example of one of the most useful contexts is
 //! [`LiteralKeyStringMapContext`], which holds entries of a `&'static str`
 //! and `String` pair structure, and stores the the [`Debug`] representation
-//! of values.
+//! of values. When an error carries many entries, or a stable order of
+//! entries is desired, [`LiteralKeyStringHashMapContext`] and
+//! [`LiteralKeyStringSortedMapContext`] offer indexed lookups along with a
+//! configurable [`DuplicatePolicy`], while [`LiteralKeyCowStringInlineMapContext`]
+//! keeps a few literal entries without allocating at all.
 //!
 //! `K` is required to implement the trait [`Kind`], specifying a general kind
 //! of the error. Although a structured error handling style is not preferred
@@ -399,30 +403,102 @@
 //! # }
 //!
 //! fn main() -> impl Termination {
-//!     Report::capture(|| {
+//!     Report::capture(|| -> AnyResult<()> {
 //!         toplevel_error()?;
 //!         Ok(())
 //!     })
 //! }
 //! ```
 //!
+//! Returning an [`AnyError`] from `main()` directly also prints the same
+//! pretty report, since the [`Debug`] implementation of [`AnyError`] renders
+//! errors in [`Report`]'s layout by default:
+//!
+//! ```no_run,rust
+//! # mod err {
+//! #     use anyerr::AnyError as AnyErrorTemplate;
+//! #     use anyerr::context::LiteralKeyStringMapContext;
+//! #
+//! #     pub use anyerr::{Intermediate, Overlay};
+//! #     pub use anyerr::kind::DefaultErrorKind as ErrKind;
+//! #     pub use anyerr::Report;
+//! #
+//! #     pub type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, ErrKind>;
+//! #     pub type AnyResult<T> = Result<T, AnyError>;
+//! # }
+//! #
+//! use err::*;
+//!
+//! fn main() -> AnyResult<()> {
+//!     Err(AnyError::minimal("an error occurred"))
+//! }
+//! ```
+//!
 //! For more information about error reporting customization, see the
-//! documentations of [`Report`].
+//! documentations of [`Report`] and [`report::set_hook()`].
 //!
 //! ## Advanced Usage
 //!
 //! ### Different Context Types
 //!
 //! This crate allows using different context types, such as
-//! [`SingletonContext`], [`StringContext`], [`AnyContext`] or the ones you
-//! developed by yourself, depending on how you want to manage and retrieve
-//! additional information from your errors. It's even viable that you don't
-//! want your error type to carry a context storage, through the [`NoContext`]
-//! trait. Each context type offers unique capabilities for structuring error
-//! metadata.
+//! [`SingletonContext`], [`StringContext`], [`AnyContext`], [`ValueContext`]
+//! or the ones you developed by yourself, depending on how you want to manage
+//! and retrieve additional information from your errors. It's even viable
+//! that you don't want your error type to carry a context storage, through
+//! the [`NoContext`] trait. Each context type offers unique capabilities for
+//! structuring error metadata.
 //!
 //! For more information, refer to the types in the [`crate::context`] module.
 //!
+//! ### Ambient Context
+//!
+//! Context shared by all errors of a unit of work, such as a request ID, can
+//! be attached once through [`scope()`]. While the returned [`Scope`] guard
+//! is alive, errors created from scratch on the current thread carry that
+//! context automatically. For asynchronous tasks which may move between
+//! threads, [`WithErrorContext::with_error_context()`] installs an
+//! [`AmbientContext`] during each poll of a future instead.
+//!
+//! ### Retrying Operations
+//!
+//! Error kinds can tell whether a failed operation is worth retrying, see
+//! [`Kind::is_retryable()`]. The [`Retry`] helper reruns a fallible operation
+//! with a [`Backoff`] policy until it succeeds, a non-retryable error occurs,
+//! or the attempts are used up, and aggregates the errors of all attempts.
+//!
+//! ### Crossing Service Boundaries
+//!
+//! Error kinds implementing [`StatusKind`] can be converted from and to the
+//! canonical RPC status [`Code`]s, through [`AnyError::code()`] and
+//! [`AnyError::from_status()`]. With the `http` feature enabled, HTTP status
+//! codes are supported in the same way.
+//!
+//! ### Help and Notes
+//!
+//! Besides the cause chain, errors can carry actionable guidance for users.
+//! Attach help messages and notes to any layer through
+//! [`AnyErrorBuilder::help()`] or [`Intermediate::help()`], and pretty
+//! reports will print them in a trailing section, while
+//! [`AnyError::helps()`] returns them for your own rendering.
+//!
+//! ### Source Code Diagnostics
+//!
+//! Errors from parsers of configuration files or other languages can point at
+//! the exact place in the source text with a [`SourceSpan`], attached through
+//! [`AnyErrorBuilder::source_span()`]. Pretty reports render the surrounding
+//! lines with the span underlined and labeled, compact reports print the
+//! line, column and byte range, and [`AnyError::source_spans_value()`] collects the spans
+//! into a structured value for JSON output.
+//!
+//! ### Error Codes
+//!
+//! Stable [`ErrorCode`]s, such as `E1042`, can be published for error kinds
+//! through [`Kind::error_code()`], or attached to a single error with
+//! [`AnyErrorBuilder::error_code()`], and reports print them along with their help
+//! links. Collect the codes into a [`Catalog`] to export them as Markdown or
+//! JSON for your documentation.
+//!
 //! ### Usage without an Error Kind
 //!
 //! For some reasons, you may not want each error to have an error kind. This
@@ -430,6 +506,17 @@
 //! its default value. By selecting [`NoErrorKind`], you no longer need to
 //! do anything with error kinds.
 //!
+//! ## Optional Features
+//!
+//! - `http`: Enables the `http` module, which maps error kinds to HTTP status
+//!   codes and renders errors into RFC 7807 problem details.
+//! - `log`: Enables `Report::log()`, which emits error reports as structured
+//!   records through the [`log`](https://docs.rs/log) crate.
+//! - `tracing`: Captures a `SpanTrace` whenever an error is created, which is
+//!   shown in reports under "In spans:", and enables `AnyError::record()`,
+//!   which emits errors as structured events through the
+//!   [`tracing`](https://docs.rs/tracing) crate.
+//!
 //! [`Any`]: std::any::Any
 //! [`AbstractContext`]: crate::context::AbstractContext
 //! [`Context`]: crate::context::Context
@@ -438,19 +525,54 @@
 //! [`Error`]: std::error::Error
 //! [`Kind`]: crate::kind::Kind
 //! [`LiteralKeyStringMapContext`]: crate::context::LiteralKeyStringMapContext
+//! [`LiteralKeyStringHashMapContext`]: crate::context::LiteralKeyStringHashMapContext
+//! [`LiteralKeyStringSortedMapContext`]: crate::context::LiteralKeyStringSortedMapContext
+//! [`DuplicatePolicy`]: crate::context::DuplicatePolicy
+//! [`LiteralKeyCowStringInlineMapContext`]: crate::context::LiteralKeyCowStringInlineMapContext
 //! [`NoContext`]: crate::context::NoContext
 //! [`NoErrorKind`]: crate::kind::NoErrorKind
 //! [`SingletonContext`]: crate::context::SingletonContext
 //! [`StringContext`]: crate::context::StringContext
 //! [`AnyContext`]: crate::context::AnyContext
+//! [`ValueContext`]: crate::context::ValueContext
+//! [`Scope`]: crate::ambient::Scope
+//! [`Retry`]: crate::retry::Retry
+//! [`StatusKind`]: crate::status::StatusKind
+//! [`Code`]: crate::status::Code
+//! [`AnyError::code()`]: crate::AnyError::code
+//! [`AnyError::from_status()`]: crate::AnyError::from_status
+//! [`Kind::is_retryable()`]: crate::kind::Kind::is_retryable
+//! [`Backoff`]: crate::retry::Backoff
+//! [`AmbientContext`]: crate::ambient::AmbientContext
+//! [`ErrorCode`]: crate::code::ErrorCode
+//! [`Catalog`]: crate::code::Catalog
+//! [`Kind::error_code()`]: crate::kind::Kind::error_code
+//! [`AnyErrorBuilder::error_code()`]: crate::core::AnyErrorBuilder::error_code
+//! [`AnyErrorBuilder::help()`]: crate::core::AnyErrorBuilder::help
+//! [`Intermediate::help()`]: crate::overlay::Intermediate::help
+//! [`AnyError::helps()`]: crate::AnyError::helps
+//! [`SourceSpan`]: crate::diagnostic::SourceSpan
+//! [`AnyError::source_spans_value()`]: crate::AnyError::source_spans_value
+//! [`AnyErrorBuilder::source_span()`]: crate::core::AnyErrorBuilder::source_span
 
+pub mod ambient;
+pub mod code;
 pub mod context;
 pub mod converter;
 pub mod core;
+pub mod diagnostic;
+#[cfg(feature = "http")]
+pub mod http;
 pub mod kind;
 pub mod overlay;
 pub mod report;
+pub mod retry;
+pub mod status;
+
+#[cfg(test)]
+mod test_util;
 
+pub use ambient::{scope, WithErrorContext};
 pub use core::AnyError;
-pub use overlay::{Intermediate, Overlay};
+pub use overlay::{Intermediate, Overlay, WithContext};
 pub use report::Report;
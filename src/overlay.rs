@@ -1,6 +1,10 @@
 pub mod error;
 pub mod result;
 
+use crate::code::ErrorCode;
+use crate::context::{AbstractContext, Context};
+use crate::converter::Convertable;
+
 /// The type that can wrap others of the same type recursively with additional
 /// data attached.
 ///
@@ -85,10 +89,89 @@ pub trait Intermediate: Sized {
         (key, value).apply(self)
     }
 
+    /// Attaches a stable error code to the final output. See
+    /// [`AnyErrorBuilder::error_code()`] for more information.
+    ///
+    /// [`AnyErrorBuilder::error_code()`]: crate::core::AnyErrorBuilder::error_code
+    fn error_code(self, code: ErrorCode) -> Self
+    where
+        ErrorCode: Applicable<Self, Output = Self>,
+    {
+        code.apply(self)
+    }
+
+    /// Attaches a help message, which tells how to fix the error, to the
+    /// final output. Multiple help messages can be attached.
+    fn help<S: Into<String>>(self, help: S) -> Self
+    where
+        Help: Applicable<Self, Output = Self>,
+    {
+        Help(help.into()).apply(self)
+    }
+
+    /// Attaches a note, which explains the error in more detail, to the final
+    /// output. Multiple notes can be attached.
+    fn note<S: Into<String>>(self, note: S) -> Self
+    where
+        Note: Applicable<Self, Output = Self>,
+    {
+        Note(note.into()).apply(self)
+    }
+
     /// Instantiates the output with all provided data.
     fn build(self) -> Self::Output;
 }
 
+/// The type whose error can be enriched with additional context in place.
+///
+/// Unlike [`Overlay`], which always wraps the error with a new layer carrying
+/// a new message, [`WithContext`] attaches context to the outermost layer of
+/// the existing error and leaves everything else untouched.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::{AnyError as AnyErrorTemplate, WithContext};
+/// # use anyerr::kind::DefaultErrorKind;
+/// # use anyerr::context::LiteralKeyStringMapContext;
+/// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+///
+/// fn parse_text(text: &str) -> Result<u32, AnyError> {
+///     text.parse::<u32>()
+///         .map_err(AnyError::wrap)
+///         .with_context("text", text)
+/// }
+///
+/// let err = parse_text("-1").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid digit found in string");
+/// assert_eq!(err.get("text"), Some("\"-1\""));
+/// ```
+pub trait WithContext: Sized {
+    /// The context where the additional context is stored.
+    type Context: Context;
+
+    /// Attaches context represented as a key-value pair to the error.
+    fn with_context<Q, R>(self, key: Q, value: R) -> Self
+    where
+        Q: Into<<Self::Context as AbstractContext>::Key>,
+        R: Convertable<
+            <Self::Context as Context>::Converter,
+            <Self::Context as AbstractContext>::Value,
+        >;
+}
+
+/// A help message which tells how to fix an error, such as "try `--force`".
+///
+/// See [`Intermediate::help()`] for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Help(pub String);
+
+/// A note which explains an error in more detail.
+///
+/// See [`Intermediate::note()`] for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Note(pub String);
+
 /// The helper which determines whether a type can be applied to the target.
 pub trait Applicable<T> {
     /// The type of the result produced by applying the value to the target.
@@ -1,8 +1,9 @@
+use crate::code::ErrorCode;
 use crate::context::{AbstractContext, Context};
 use crate::converter::Convertable;
 use crate::core::{AnyError, AnyErrorBuilder};
 use crate::kind::Kind;
-use crate::overlay::{Applicable, Intermediate, Overlay};
+use crate::overlay::{Applicable, Help, Intermediate, Note, Overlay, WithContext};
 
 impl<C, K> Overlay for AnyError<C, K>
 where
@@ -118,6 +119,62 @@ where
     }
 }
 
+impl<C, K> Applicable<IntermediateAnyError<C, K>> for ErrorCode
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = IntermediateAnyError<C, K>;
+
+    /// Attaches the error code to `target`.
+    fn apply(self, target: IntermediateAnyError<C, K>) -> Self::Output {
+        target.builder.error_code(self).into()
+    }
+}
+
+impl<C, K> Applicable<IntermediateAnyError<C, K>> for Help
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = IntermediateAnyError<C, K>;
+
+    /// Attaches the help message to `target`.
+    fn apply(self, target: IntermediateAnyError<C, K>) -> Self::Output {
+        target.builder.help(self.0).into()
+    }
+}
+
+impl<C, K> Applicable<IntermediateAnyError<C, K>> for Note
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = IntermediateAnyError<C, K>;
+
+    /// Attaches the note to `target`.
+    fn apply(self, target: IntermediateAnyError<C, K>) -> Self::Output {
+        target.builder.note(self.0).into()
+    }
+}
+
+impl<C, K> WithContext for AnyError<C, K>
+where
+    C: Context,
+    K: Kind,
+{
+    type Context = C;
+
+    /// Delegates the parameters to [`AnyError::attach_context()`].
+    fn with_context<Q, R>(self, key: Q, value: R) -> Self
+    where
+        Q: Into<C::Key>,
+        R: Convertable<C::Converter, C::Value>,
+    {
+        self.attach_context(key, value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -165,6 +222,59 @@ mod tests {
         assert_eq!(err.get("key"), Some("\"value\""));
     }
 
+    #[test]
+    fn intermediate_any_error_error_code_succeeds() {
+        const CODE: ErrorCode = ErrorCode::new("E0001", "the wrapper error");
+        let err = DefaultAnyError::minimal("source error")
+            .overlay("wrapper error")
+            .error_code(CODE)
+            .context("key", "value")
+            .build();
+        assert_eq!(err.error_code(), Some(CODE));
+        assert_eq!(err.get("key"), Some("\"value\""));
+    }
+
+    #[test]
+    fn intermediate_any_error_help_and_note_succeed() {
+        let err = DefaultAnyError::minimal("source error")
+            .overlay("wrapper error")
+            .help("try again later")
+            .note("the service is under maintenance")
+            .help("contact the administrator")
+            .build();
+        assert_eq!(
+            err.helps().collect::<Vec<_>>(),
+            ["try again later", "contact the administrator"]
+        );
+        assert_eq!(
+            err.notes().collect::<Vec<_>>(),
+            ["the service is under maintenance"]
+        );
+    }
+
+    #[test]
+    fn any_error_with_context_succeeds() {
+        let err = DefaultAnyError::minimal("source error")
+            .with_context("key1", "value")
+            .with_context("key2", 2);
+        assert_eq!(err.to_string(), "source error");
+        assert!(err.source().is_none());
+        assert_eq!(err.get("key1"), Some("\"value\""));
+        assert_eq!(err.get("key2"), Some("2"));
+
+        let err = DefaultAnyError::wrap("".parse::<u32>().unwrap_err()).with_context("key", 1);
+        assert!(err.is::<std::num::ParseIntError>());
+        assert_eq!(err.get("key"), Some("1"));
+
+        let err = err
+            .overlay("wrapper error")
+            .build()
+            .with_context("outer", 2);
+        assert_eq!(err.get("outer"), Some("2"));
+        assert_eq!(err.get("key"), None);
+        assert_eq!(err.get_deep("key"), Some("1"));
+    }
+
     #[test]
     fn intermediate_any_error_into_any_error_succeeds_with_try_operator() {
         fn source_error_func() -> Result<(), DefaultAnyError> {
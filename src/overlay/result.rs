@@ -1,9 +1,10 @@
+use crate::code::ErrorCode;
 use crate::context::{AbstractContext, Context};
 use crate::converter::Convertable;
 use crate::core::AnyError;
 use crate::kind::Kind;
 use crate::overlay::error::IntermediateAnyError;
-use crate::overlay::{Applicable, Intermediate, Overlay};
+use crate::overlay::{Applicable, Help, Intermediate, Note, Overlay, WithContext};
 
 impl<T, C, K> Overlay for Result<T, AnyError<C, K>>
 where
@@ -82,6 +83,62 @@ where
     }
 }
 
+impl<T, C, K> Applicable<Result<T, IntermediateAnyError<C, K>>> for ErrorCode
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = Result<T, IntermediateAnyError<C, K>>;
+
+    /// Delegates the parameters to [`IntermediateAnyError`]'s implementation.
+    fn apply(self, target: Result<T, IntermediateAnyError<C, K>>) -> Self::Output {
+        target.map_err(|err| self.apply(err))
+    }
+}
+
+impl<T, C, K> Applicable<Result<T, IntermediateAnyError<C, K>>> for Help
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = Result<T, IntermediateAnyError<C, K>>;
+
+    /// Delegates the parameters to [`IntermediateAnyError`]'s implementation.
+    fn apply(self, target: Result<T, IntermediateAnyError<C, K>>) -> Self::Output {
+        target.map_err(|err| self.apply(err))
+    }
+}
+
+impl<T, C, K> Applicable<Result<T, IntermediateAnyError<C, K>>> for Note
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    type Output = Result<T, IntermediateAnyError<C, K>>;
+
+    /// Delegates the parameters to [`IntermediateAnyError`]'s implementation.
+    fn apply(self, target: Result<T, IntermediateAnyError<C, K>>) -> Self::Output {
+        target.map_err(|err| self.apply(err))
+    }
+}
+
+impl<T, C, K> WithContext for Result<T, AnyError<C, K>>
+where
+    C: Context,
+    K: Kind,
+{
+    type Context = C;
+
+    /// Delegates the parameters to [`AnyError`]'s implementation.
+    fn with_context<Q, R>(self, key: Q, value: R) -> Self
+    where
+        Q: Into<C::Key>,
+        R: Convertable<C::Converter, C::Value>,
+    {
+        self.map_err(|err| err.with_context(key, value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -128,6 +185,18 @@ mod tests {
         assert_eq!(res.unwrap(), 1);
     }
 
+    #[test]
+    fn result_with_context_succeeds() {
+        let source = Err::<(), _>(DefaultAnyError::minimal("source error"));
+        let err = source.with_context("i32", 1).unwrap_err();
+        assert_eq!(err.to_string(), "source error");
+        assert!(err.source().is_none());
+        assert_eq!(err.get("i32"), Some("1"));
+
+        let source = Ok::<i32, DefaultAnyError>(1);
+        assert_eq!(source.with_context("i32", 1).unwrap(), 1);
+    }
+
     #[test]
     fn intermediate_result_context_succeeds() {
         let source = Err::<(), _>(DefaultAnyError::minimal("source error"));
@@ -141,4 +210,39 @@ mod tests {
         assert_eq!(err.get("i32"), Some("1"));
         assert_eq!(err.get("&str"), Some("\"value\""));
     }
+
+    #[test]
+    fn intermediate_result_help_and_note_succeed() {
+        let source = Err::<(), _>(DefaultAnyError::minimal("source error"));
+        let res = source
+            .overlay("wrapper error")
+            .help("check that the port is free")
+            .context("port", 8080)
+            .note("ports below 1024 require privileges")
+            .build();
+        let err = res.unwrap_err();
+        assert_eq!(
+            err.helps().collect::<Vec<_>>(),
+            ["check that the port is free"]
+        );
+        assert_eq!(
+            err.notes().collect::<Vec<_>>(),
+            ["ports below 1024 require privileges"]
+        );
+        assert_eq!(err.get("port"), Some("8080"));
+    }
+
+    #[test]
+    fn intermediate_result_error_code_succeeds() {
+        const CODE: ErrorCode = ErrorCode::new("E0001", "the wrapper error");
+        let source = Err::<(), _>(DefaultAnyError::minimal("source error"));
+        let res = source
+            .overlay("wrapper error")
+            .error_code(CODE)
+            .context("key", "value")
+            .build();
+        let err = res.unwrap_err();
+        assert_eq!(err.error_code(), Some(CODE));
+        assert_eq!(err.get("key"), Some("\"value\""));
+    }
 }
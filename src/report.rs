@@ -1,14 +1,24 @@
+mod hook;
 mod inner;
+mod render;
 
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::future::Future;
+use std::io::{self, Write};
 use std::process::{ExitCode, Termination};
 
-use crate::context::AbstractContext;
+use crate::context::{AbstractContext, MergePolicy};
 use crate::kind::Kind;
 use crate::AnyError;
 
 use inner::ReportInner;
 
+pub use hook::{set_hook, DebugStyle, SetHookError};
+pub use render::ReportOptions;
+
+pub(crate) use hook::debug_style;
+pub(crate) use render::Renderer;
+
 /// An error reporter which displays data carried by an [`AnyError`].
 ///
 /// [`Report`] captures your function's result, such as [`Result<(), AnyError>`],
@@ -32,10 +42,14 @@ where
 
     /// Captures the result of a given function and creates a [`Report`].
     ///
+    /// The function may return any value implementing [`Termination`] on
+    /// success, which determines the exit code of the process, and any error
+    /// which can be converted into an [`AnyError`] on failure.
+    ///
     /// # Example
     ///
     /// ```no_run,rust
-    /// # use std::process::Termination;
+    /// # use std::process::{ExitCode, Termination};
     /// # use anyerr::AnyError as AnyErrorTemplate;
     /// # use anyerr::kind::DefaultErrorKind;
     /// # use anyerr::context::LiteralKeyStringMapContext;
@@ -43,16 +57,49 @@ where
     /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
     ///
     /// fn main() -> impl Termination {
-    ///     Report::capture(|| -> Result<(), AnyError> {
+    ///     Report::capture(|| -> Result<ExitCode, AnyError> {
     ///         Err(AnyError::minimal("an error occurred"))
     ///     })
     /// }
     /// ```
-    pub fn capture(func: impl FnOnce() -> Result<(), AnyError<C, K>>) -> Self {
-        match func() {
-            Ok(_) => Self(ReportVariant::Success),
-            Err(err) => err.into(),
-        }
+    pub fn capture<F, T, E>(func: F) -> Self
+    where
+        F: FnOnce() -> Result<T, E>,
+        T: Termination,
+        E: Into<AnyError<C, K>>,
+    {
+        Self::from_result(func())
+    }
+
+    /// Captures the result of a given future and creates a [`Report`], which
+    /// is the asynchronous version of [`Report::capture()`] and is useful for
+    /// the `main()` function of an asynchronous runtime.
+    ///
+    /// # Example
+    ///
+    /// ```no_run,rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::report::Report;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    ///
+    /// async fn run() -> Result<(), AnyError> {
+    ///     Err(AnyError::minimal("an error occurred"))
+    /// }
+    ///
+    /// // This is typically your `main()` annotated with your runtime's macro.
+    /// async fn async_main() -> Report<LiteralKeyStringMapContext, DefaultErrorKind> {
+    ///     Report::capture_future(run()).await
+    /// }
+    /// ```
+    pub async fn capture_future<F, T, E>(future: F) -> Self
+    where
+        F: Future<Output = Result<T, E>>,
+        T: Termination,
+        E: Into<AnyError<C, K>>,
+    {
+        Self::from_result(future.await)
     }
 
     /// Prints a pretty error report if `pretty` is `true`, otherwise prints
@@ -72,6 +119,23 @@ where
         }
     }
 
+    /// Prints the error code and its help link if `error_code` is `true`.
+    pub fn error_code(self, error_code: bool) -> Self {
+        match self.0 {
+            ReportVariant::Failure(report) => report.error_code(error_code).into(),
+            v => Self(v),
+        }
+    }
+
+    /// Prints the attached help messages and notes if `help` is `true`,
+    /// which only takes effect in pretty reports.
+    pub fn help(self, help: bool) -> Self {
+        match self.0 {
+            ReportVariant::Failure(report) => report.help(help).into(),
+            v => Self(v),
+        }
+    }
+
     /// Prints the backtrace if `backtrace` is `true`.
     pub fn backtrace(self, backtrace: bool) -> Self {
         match self.0 {
@@ -80,6 +144,16 @@ where
         }
     }
 
+    /// Prints the span trace if `span_trace` is `true`, which is only
+    /// available with the `tracing` feature enabled.
+    #[cfg(feature = "tracing")]
+    pub fn span_trace(self, span_trace: bool) -> Self {
+        match self.0 {
+            ReportVariant::Failure(report) => report.span_trace(span_trace).into(),
+            v => Self(v),
+        }
+    }
+
     /// Prints the attached context if `context` is `true`.
     pub fn context(self, context: bool) -> Self {
         match self.0 {
@@ -88,6 +162,98 @@ where
         }
     }
 
+    /// Merges context entries sharing the same key according to
+    /// `merge_policy`, which shows all entries by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::{AnyError as AnyErrorTemplate, Intermediate, Overlay};
+    /// # use anyerr::context::{LiteralKeyStringMapContext, MergePolicy};
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::report::Report;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    ///
+    /// let err = AnyError::builder()
+    ///     .message("inner")
+    ///     .context("client-addr", "127.0.0.1")
+    ///     .build()
+    ///     .overlay("outer")
+    ///     .context("client-addr", "localhost")
+    ///     .build();
+    /// let report = Report::wrap(err)
+    ///     .pretty(false)
+    ///     .kind(false)
+    ///     .merge_policy(MergePolicy::InnermostWins);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     r#"outer: inner [client-addr = "127.0.0.1"]"#
+    /// );
+    /// ```
+    pub fn merge_policy(self, merge_policy: MergePolicy) -> Self {
+        match self.0 {
+            ReportVariant::Failure(report) => report.merge_policy(merge_policy).into(),
+            v => Self(v),
+        }
+    }
+
+    /// Writes the report followed by a newline to `writer`. Nothing is
+    /// written if no error occurred.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing to `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::report::Report;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    ///
+    /// let report = Report::wrap(AnyError::minimal("an error occurred")).pretty(false);
+    /// let mut buffer = Vec::new();
+    /// report.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"(Unknown) an error occurred\n");
+    /// ```
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        match &self.0 {
+            ReportVariant::Failure(report) => writeln!(writer, "{report}"),
+            _ => Ok(()),
+        }
+    }
+
+    /// Emits the report as a single record through the [`log`] crate with the
+    /// given `level`. Nothing is emitted if no error occurred.
+    ///
+    /// The record's message is the rendered report, and the following
+    /// structured key-values are attached:
+    ///
+    /// - `kind`: the kind of the outermost error
+    /// - `message`: the message of the outermost error
+    /// - `chain`: the messages of the whole error chain in one line
+    ///
+    /// [`log`]: https://docs.rs/log
+    #[cfg(feature = "log")]
+    pub fn log(&self, level: log::Level) {
+        if let ReportVariant::Failure(report) = &self.0 {
+            report.log(level);
+        }
+    }
+
+    fn from_result<T, E>(result: Result<T, E>) -> Self
+    where
+        T: Termination,
+        E: Into<AnyError<C, K>>,
+    {
+        match result {
+            Ok(value) => Self(ReportVariant::Success(value.report())),
+            Err(err) => Self::from(err.into()),
+        }
+    }
+
     fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.0 {
             ReportVariant::Failure(report) => report.render(f),
@@ -123,9 +289,9 @@ where
 {
     fn report(self) -> ExitCode {
         match self.0 {
-            ReportVariant::Success => ExitCode::SUCCESS,
-            ReportVariant::Failure(report) => {
-                eprintln!("{report}");
+            ReportVariant::Success(code) => code,
+            ReportVariant::Failure(_) => {
+                let _ = self.write_to(&mut io::stderr().lock());
                 ExitCode::FAILURE
             }
         }
@@ -157,6 +323,145 @@ where
     C: AbstractContext<Entry: Display>,
     K: Kind,
 {
-    Success,
+    Success(ExitCode),
     Failure(ReportInner<C, K>),
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::context::StringKeyStringMapContext;
+    use crate::kind::DefaultErrorKind as ErrKind;
+    use crate::test_util::block_on;
+
+    use super::*;
+
+    type TestError = AnyError<StringKeyStringMapContext, ErrKind>;
+    type TestReport = Report<StringKeyStringMapContext, ErrKind>;
+
+    #[derive(Debug)]
+    struct ForeignError;
+
+    impl Display for ForeignError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(f, "foreign error")
+        }
+    }
+
+    impl From<ForeignError> for TestError {
+        fn from(_: ForeignError) -> Self {
+            TestError::minimal("converted error")
+        }
+    }
+
+    #[test]
+    fn report_capture_succeeds_when_function_succeeds() {
+        let report = TestReport::capture(|| Ok::<_, TestError>(()));
+        assert_eq!(report.to_string(), "");
+        assert_eq!(report.report(), ExitCode::SUCCESS);
+
+        let report = TestReport::capture(|| Ok::<_, TestError>(ExitCode::from(42)));
+        assert_eq!(report.report(), ExitCode::from(42));
+    }
+
+    #[test]
+    fn report_capture_succeeds_when_function_fails() {
+        let report =
+            TestReport::capture(|| Err::<(), _>(TestError::minimal("error"))).pretty(false);
+        assert_eq!(report.to_string(), "(Unknown) error");
+
+        let report = TestReport::capture(|| Err::<ExitCode, _>(ForeignError)).pretty(false);
+        assert_eq!(report.to_string(), "(Unknown) converted error");
+        assert_eq!(report.report(), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn report_capture_future_succeeds() {
+        let report = block_on(TestReport::capture_future(async { Ok::<_, TestError>(()) }));
+        assert_eq!(report.report(), ExitCode::SUCCESS);
+
+        let report = block_on(TestReport::capture_future(async {
+            Err::<(), _>(ForeignError)
+        }))
+        .pretty(false);
+        assert_eq!(report.to_string(), "(Unknown) converted error");
+    }
+
+    #[test]
+    fn report_write_to_succeeds() {
+        let mut buffer = Vec::new();
+        let report = TestReport::capture(|| Ok::<_, TestError>(()));
+        report.write_to(&mut buffer).unwrap();
+        assert!(buffer.is_empty());
+
+        let report = TestReport::wrap(TestError::minimal("error")).pretty(false);
+        report.write_to(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "(Unknown) error\n");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn report_log_succeeds() {
+        use std::sync::Mutex;
+
+        use log::kv::{Error as KvError, Key, Value, VisitSource};
+        use log::{Level, LevelFilter, Log, Metadata, Record};
+
+        use crate::{Intermediate, Overlay};
+
+        type LogRecord = (Level, String, Vec<(String, String)>);
+
+        struct TestLogger(Mutex<Vec<LogRecord>>);
+
+        impl Log for TestLogger {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &Record<'_>) {
+                struct Visitor(Vec<(String, String)>);
+
+                impl<'kvs> VisitSource<'kvs> for Visitor {
+                    fn visit_pair(
+                        &mut self,
+                        key: Key<'kvs>,
+                        value: Value<'kvs>,
+                    ) -> Result<(), KvError> {
+                        self.0.push((key.to_string(), value.to_string()));
+                        Ok(())
+                    }
+                }
+
+                let mut visitor = Visitor(Vec::new());
+                record.key_values().visit(&mut visitor).unwrap();
+                let entry = (record.level(), record.args().to_string(), visitor.0);
+                self.0.lock().unwrap().push(entry);
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        TestReport::capture(|| Ok::<_, TestError>(())).log(Level::Error);
+        let error = TestError::quick("inner", ErrKind::ValueValidation)
+            .overlay("outer")
+            .build();
+        TestReport::wrap(error).pretty(false).log(Level::Warn);
+
+        let records = LOGGER.0.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let (level, message, key_values) = &records[0];
+        assert_eq!(*level, Level::Warn);
+        assert_eq!(message, "(Unknown) outer: (ValueValidation) inner");
+        assert_eq!(
+            *key_values,
+            vec![
+                ("kind".into(), "Unknown".into()),
+                ("message".into(), "outer".into()),
+                ("chain".into(), "outer: inner".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::OnceLock;
+
+use crate::report::render::ReportOptions;
+
+type Hook = Box<dyn Fn(&(dyn Error + 'static)) -> DebugStyle + Send + Sync + 'static>;
+
+static HOOK: OnceLock<Hook> = OnceLock::new();
+
+/// The way an [`AnyError`] is rendered by its [`Debug`] implementation.
+///
+/// [`AnyError`]: crate::AnyError
+/// [`Debug`]: std::fmt::Debug
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugStyle {
+    /// Renders the error in the same layout as [`Report`] does with the given
+    /// options.
+    ///
+    /// [`Report`]: crate::report::Report
+    Report(ReportOptions),
+    /// Renders the internal structure of the error, which is mostly useful
+    /// when debugging this crate itself.
+    Raw,
+}
+
+impl Default for DebugStyle {
+    fn default() -> Self {
+        Self::Report(ReportOptions::new())
+    }
+}
+
+/// Installs a global hook which decides how [`AnyError`] is rendered by its
+/// [`Debug`] implementation.
+///
+/// The hook receives the error being formatted and returns a [`DebugStyle`].
+/// If no hook is installed, [`DebugStyle::default()`] is used, which renders
+/// the error as a pretty [`Report`] does. That's what you'll see when an
+/// [`AnyError`] is returned from `main()` or passed to [`Result::unwrap()`].
+///
+/// The hook can only be installed once, typically at the very beginning of
+/// `main()`.
+///
+/// # Errors
+///
+/// This function will return [`SetHookError`] if a hook has already been
+/// installed.
+///
+/// # Example
+///
+/// ```rust
+/// # use anyerr::report::{self, DebugStyle, ReportOptions};
+/// report::set_hook(|_| DebugStyle::Report(ReportOptions::new().backtrace(false))).unwrap();
+/// assert!(report::set_hook(|_| DebugStyle::Raw).is_err());
+/// ```
+///
+/// [`AnyError`]: crate::AnyError
+/// [`Debug`]: std::fmt::Debug
+/// [`Report`]: crate::report::Report
+pub fn set_hook<F>(hook: F) -> Result<(), SetHookError>
+where
+    F: Fn(&(dyn Error + 'static)) -> DebugStyle + Send + Sync + 'static,
+{
+    HOOK.set(Box::new(hook)).map_err(|_| SetHookError)
+}
+
+pub(crate) fn debug_style(error: &(dyn Error + 'static)) -> DebugStyle {
+    HOOK.get()
+        .map_or_else(DebugStyle::default, |hook| hook(error))
+}
+
+/// The error returned by [`set_hook()`] when a hook has already been
+/// installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetHookError;
+
+impl Display for SetHookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "the report hook has already been installed")
+    }
+}
+
+impl Error for SetHookError {}
@@ -1,9 +1,8 @@
-use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
-use crate::context::AbstractContext;
-use crate::core::ContextDepth;
+use crate::context::{AbstractContext, MergePolicy};
 use crate::kind::Kind;
+use crate::report::render::{Renderer, ReportOptions};
 use crate::AnyError;
 
 pub struct ReportInner<C, K>
@@ -12,10 +11,7 @@ where
     K: Kind,
 {
     error: AnyError<C, K>,
-    pretty: bool,
-    kind: bool,
-    backtrace: bool,
-    context: bool,
+    options: ReportOptions,
 }
 
 impl<C, K> ReportInner<C, K>
@@ -24,168 +20,60 @@ where
     K: Kind,
 {
     pub fn pretty(self, pretty: bool) -> Self {
-        Self { pretty, ..self }
+        let options = self.options.pretty(pretty);
+        Self { options, ..self }
     }
 
     pub fn kind(self, kind: bool) -> Self {
-        Self { kind, ..self }
+        let options = self.options.kind(kind);
+        Self { options, ..self }
     }
 
-    pub fn backtrace(self, backtrace: bool) -> Self {
-        Self { backtrace, ..self }
-    }
-
-    pub fn context(self, context: bool) -> Self {
-        Self { context, ..self }
-    }
-
-    pub fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if self.pretty {
-            self.render_pretty_report(f)
-        } else {
-            self.render_compact_report(f)
-        }
+    pub fn error_code(self, error_code: bool) -> Self {
+        let options = self.options.error_code(error_code);
+        Self { options, ..self }
     }
 
-    fn render_pretty_report(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.render_single_pretty_error(f, "Error:", &self.error)?;
-
-        let mut source = self.error.source();
-        loop {
-            let error = source.and_then(|error| error.downcast_ref::<AnyError<C, K>>());
-            if let Some(error) = error {
-                self.render_single_pretty_error(f, "Caused by:", error)?;
-                source = error.source();
-            } else {
-                break;
-            }
-        }
-
-        self.render_backtrace(f)?;
-        Ok(())
-    }
-
-    fn render_single_pretty_error(
-        &self,
-        f: &mut Formatter<'_>,
-        prefix: &str,
-        error: &AnyError<C, K>,
-    ) -> FmtResult {
-        writeln!(f, "{}", prefix)?;
-        self.render_pretty_single_error_message(f, error)?;
-        self.render_pretty_one_line_context(f, error)?;
-        Ok(())
-    }
-
-    fn render_pretty_single_error_message(
-        &self,
-        f: &mut Formatter<'_>,
-        error: &AnyError<C, K>,
-    ) -> FmtResult {
-        write!(f, "    ")?;
-        if self.kind {
-            writeln!(f, "({}) {}", error.kind(), error)
-        } else {
-            writeln!(f, "{}", error)
-        }
+    pub fn help(self, help: bool) -> Self {
+        let options = self.options.help(help);
+        Self { options, ..self }
     }
 
-    fn render_pretty_one_line_context(
-        &self,
-        f: &mut Formatter<'_>,
-        error: &AnyError<C, K>,
-    ) -> FmtResult {
-        if !self.context {
-            return Ok(());
-        }
-        let mut context = error.context(ContextDepth::Shallowest).peekable();
-        if context.peek().is_none() {
-            return Ok(());
-        }
-        write!(f, "    [")?;
-        let mut first = true;
-        for entry in context {
-            if first {
-                write!(f, "{entry}")?;
-                first = false;
-            } else {
-                write!(f, ", {entry}")?;
-            }
-        }
-        writeln!(f, "]")?;
-        Ok(())
+    pub fn backtrace(self, backtrace: bool) -> Self {
+        let options = self.options.backtrace(backtrace);
+        Self { options, ..self }
     }
 
-    fn render_backtrace(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if !self.backtrace {
-            return Ok(());
-        }
-
-        writeln!(f)?;
-        writeln!(f, "Stack backtrace:")?;
-        writeln!(f, "{}", self.error.backtrace())?;
-        Ok(())
+    #[cfg(feature = "tracing")]
+    pub fn span_trace(self, span_trace: bool) -> Self {
+        let options = self.options.span_trace(span_trace);
+        Self { options, ..self }
     }
 
-    fn render_compact_report(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.render_one_line_message(f)?;
-        self.render_compact_one_line_context(f, &self.error)?;
-        Ok(())
+    pub fn context(self, context: bool) -> Self {
+        let options = self.options.context(context);
+        Self { options, ..self }
     }
 
-    fn render_one_line_message(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.render_compact_single_error_message(f, &self.error)?;
-        let mut source = self.error.source();
-        loop {
-            let error = source.and_then(|error| error.downcast_ref::<AnyError<C, K>>());
-            if let Some(error) = error {
-                write!(f, ": ")?;
-                self.render_compact_single_error_message(f, error)?;
-                source = error.source();
-            } else {
-                break;
-            }
-        }
-        Ok(())
+    pub fn merge_policy(self, merge_policy: MergePolicy) -> Self {
+        let options = self.options.merge_policy(merge_policy);
+        Self { options, ..self }
     }
 
-    fn render_compact_one_line_context(
-        &self,
-        f: &mut Formatter<'_>,
-        error: &AnyError<C, K>,
-    ) -> FmtResult {
-        if !self.context {
-            return Ok(());
-        }
-        let mut context = error.context(ContextDepth::All).peekable();
-        if context.peek().is_none() {
-            return Ok(());
-        }
-        write!(f, " ")?;
-        write!(f, "[")?;
-        let mut first = true;
-        for entry in context {
-            if first {
-                write!(f, "{entry}")?;
-                first = false;
-            } else {
-                write!(f, ", {entry}")?;
-            }
-        }
-        write!(f, "]")?;
-        Ok(())
+    pub fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Renderer::new(&self.error, &self.options).render(f)
     }
 
-    fn render_compact_single_error_message(
-        &self,
-        f: &mut Formatter<'_>,
-        error: &AnyError<C, K>,
-    ) -> FmtResult {
-        if self.kind {
-            write!(f, "({}) {}", error.kind(), error)
-        } else {
-            write!(f, "{}", error)
-        }
+    #[cfg(feature = "log")]
+    pub fn log(&self, level: log::Level) {
+        let error = &self.error;
+        log::log!(
+            level,
+            kind:% = error.kind(),
+            message:% = error,
+            chain:% = format_args!("{error:#}");
+            "{self}"
+        );
     }
 }
 
@@ -197,10 +85,7 @@ where
     fn from(error: AnyError<C, K>) -> Self {
         Self {
             error,
-            pretty: true,
-            kind: true,
-            backtrace: true,
-            context: true,
+            options: ReportOptions::new(),
         }
     }
 }
@@ -316,6 +201,227 @@ mod tests {
         );
     }
 
+    #[test]
+    fn report_inner_display_succeeds_with_merge_policy() {
+        let new_error = || {
+            TestError::builder()
+                .message("error1")
+                .context("key", "1")
+                .context("key", "2")
+                .build()
+                .overlay("error2")
+                .context("key", "3")
+                .context("other", "4")
+                .build()
+        };
+
+        let report = ReportInner::from(new_error())
+            .pretty(false)
+            .kind(false)
+            .merge_policy(MergePolicy::OutermostWins);
+        assert_eq!(
+            report.to_string(),
+            "error2: error1 [key = \"3\", other = \"4\"]"
+        );
+
+        let report = ReportInner::from(new_error())
+            .pretty(true)
+            .kind(false)
+            .backtrace(false)
+            .merge_policy(MergePolicy::InnermostWins);
+        let mut expected = String::new();
+        expected.push_str("Error:\n");
+        expected.push_str("    error2\n");
+        expected.push_str("    [other = \"4\"]\n");
+        expected.push_str("Caused by:\n");
+        expected.push_str("    error1\n");
+        expected.push_str("    [key = \"2\"]\n");
+        assert_eq!(report.to_string(), expected);
+    }
+
+    #[test]
+    fn report_inner_display_succeeds_with_error_code() {
+        use crate::code::ErrorCode;
+
+        const LOCKED: ErrorCode = ErrorCode::new("E1042", "the account is locked")
+            .with_help_url("https://example.com/errors/E1042");
+        let new_error = |code: ErrorCode| {
+            TestError::builder()
+                .message("account 42 is locked")
+                .error_code(code)
+                .build()
+                .overlay("could not log in")
+                .build()
+        };
+
+        let report = ReportInner::from(new_error(LOCKED))
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        let mut expected = String::new();
+        expected.push_str("Error[E1042]:\n");
+        expected.push_str("    could not log in\n");
+        expected.push_str("Caused by:\n");
+        expected.push_str("    account 42 is locked\n");
+        expected.push('\n');
+        expected
+            .push_str("For more information about E1042, see https://example.com/errors/E1042\n");
+        assert_eq!(report.to_string(), expected);
+
+        let report = ReportInner::from(new_error(ErrorCode::new("E1043", "undocumented")))
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        assert_eq!(
+            report.to_string(),
+            "Error[E1043]:\n    could not log in\nCaused by:\n    account 42 is locked\n"
+        );
+
+        let report = ReportInner::from(new_error(LOCKED))
+            .pretty(false)
+            .kind(false);
+        assert_eq!(
+            report.to_string(),
+            "[E1042] could not log in: account 42 is locked"
+        );
+
+        let report = report.error_code(false);
+        assert_eq!(report.to_string(), "could not log in: account 42 is locked");
+    }
+
+    #[test]
+    fn report_inner_display_succeeds_with_helps_and_notes() {
+        use crate::code::ErrorCode;
+
+        let new_error = || {
+            TestError::builder()
+                .message("address in use")
+                .note("another server may be running")
+                .build()
+                .overlay("could not bind the listener")
+                .help("check that the port is free")
+                .help("choose another port")
+                .build()
+        };
+
+        let report = ReportInner::from(new_error())
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        let mut expected = String::new();
+        expected.push_str("Error:\n");
+        expected.push_str("    could not bind the listener\n");
+        expected.push_str("Caused by:\n");
+        expected.push_str("    address in use\n");
+        expected.push('\n');
+        expected.push_str("help: check that the port is free\n");
+        expected.push_str("help: choose another port\n");
+        expected.push_str("note: another server may be running\n");
+        assert_eq!(report.to_string(), expected);
+
+        let report = report.help(false);
+        assert_eq!(
+            report.to_string(),
+            "Error:\n    could not bind the listener\nCaused by:\n    address in use\n"
+        );
+
+        let error = TestError::builder()
+            .message("address in use")
+            .error_code(
+                ErrorCode::new("E0100", "address in use").with_help_url("https://example.com"),
+            )
+            .help("check that the port is free")
+            .build();
+        let report = ReportInner::from(error)
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        let mut expected = String::new();
+        expected.push_str("Error[E0100]:\n");
+        expected.push_str("    address in use\n");
+        expected.push('\n');
+        expected.push_str("help: check that the port is free\n");
+        expected.push('\n');
+        expected.push_str("For more information about E0100, see https://example.com\n");
+        assert_eq!(report.to_string(), expected);
+
+        let report = ReportInner::from(new_error()).pretty(false).kind(false);
+        assert_eq!(
+            report.to_string(),
+            "could not bind the listener: address in use"
+        );
+    }
+
+    #[test]
+    fn report_inner_display_succeeds_with_source_spans() {
+        use crate::diagnostic::SourceSpan;
+
+        let text = "[server]\nport = \"eighty\"\n";
+        let error = TestError::builder()
+            .message("invalid port")
+            .source_span(
+                SourceSpan::new("config.toml", text, 16..24).with_label("expected an integer"),
+            )
+            .build()
+            .overlay("could not load the config")
+            .build();
+
+        let report = ReportInner::from(error)
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        let mut expected = String::new();
+        expected.push_str("Error:\n");
+        expected.push_str("    could not load the config\n");
+        expected.push_str("Caused by:\n");
+        expected.push_str("    invalid port\n");
+        expected.push_str("     --> config.toml:2:8\n");
+        expected.push_str("      |\n");
+        expected.push_str("    1 | [server]\n");
+        expected.push_str("    2 | port = \"eighty\"\n");
+        expected.push_str("      |        ^^^^^^^^ expected an integer\n");
+        assert_eq!(report.to_string(), expected);
+
+        let report = report.pretty(false);
+        assert_eq!(
+            report.to_string(),
+            "could not load the config: invalid port (at config.toml:2:8, bytes 16..24)"
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn report_inner_display_succeeds_with_span_trace() {
+        use tracing_error::ErrorLayer;
+        use tracing_subscriber::layer::SubscriberExt;
+        use tracing_subscriber::Registry;
+
+        let subscriber = Registry::default().with(ErrorLayer::default());
+        let error = tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("handle_request", id = 42).entered();
+            TestError::minimal("error")
+        });
+
+        let report = ReportInner::from(error)
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        let rendered = report.to_string();
+        assert!(rendered.starts_with("Error:\n    error\n\nIn spans:\n"));
+        assert!(rendered.contains("handle_request"));
+        assert!(rendered.contains("id=42"));
+
+        let report = report.span_trace(false);
+        assert_eq!(report.to_string(), "Error:\n    error\n");
+
+        let report = ReportInner::from(TestError::minimal("error"))
+            .pretty(true)
+            .kind(false)
+            .backtrace(false);
+        assert_eq!(report.to_string(), "Error:\n    error\n");
+    }
+
+    #[allow(clippy::let_and_return)]
     fn new_test_error() -> TestError {
         let error1 = TestError::quick("error1", ErrKind::ValueValidation);
         let error2 = error1
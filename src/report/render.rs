@@ -0,0 +1,410 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "tracing")]
+use tracing_error::SpanTraceStatus;
+
+use crate::code::ErrorCode;
+use crate::context::{AbstractContext, Entry, MergePolicy, MergedContext};
+use crate::core::ContextDepth;
+use crate::kind::Kind;
+use crate::AnyError;
+
+/// The options which control what an error report contains and how it's laid
+/// out.
+///
+/// [`ReportOptions`] is shared by [`Report`] and the [`Debug`] implementation
+/// of [`AnyError`], whose behavior can be customized with [`set_hook()`]. All
+/// options are enabled by default, and all context entries are shown even if
+/// some of them share the same key.
+///
+/// [`Report`]: crate::report::Report
+/// [`Debug`]: std::fmt::Debug
+/// [`set_hook()`]: crate::report::set_hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportOptions {
+    pretty: bool,
+    kind: bool,
+    error_code: bool,
+    help: bool,
+    backtrace: bool,
+    #[cfg(feature = "tracing")]
+    span_trace: bool,
+    context: bool,
+    merge_policy: MergePolicy,
+}
+
+impl ReportOptions {
+    /// Creates a [`ReportOptions`] with all options enabled.
+    pub const fn new() -> Self {
+        Self {
+            pretty: true,
+            kind: true,
+            error_code: true,
+            help: true,
+            backtrace: true,
+            #[cfg(feature = "tracing")]
+            span_trace: true,
+            context: true,
+            merge_policy: MergePolicy::All,
+        }
+    }
+
+    /// Prints a pretty error report if `pretty` is `true`, otherwise prints
+    /// a compact error report in one line.
+    pub const fn pretty(self, pretty: bool) -> Self {
+        Self { pretty, ..self }
+    }
+
+    /// Prints error kinds if `kind` is `true`.
+    pub const fn kind(self, kind: bool) -> Self {
+        Self { kind, ..self }
+    }
+
+    /// Prints the error code and its help link if `error_code` is `true`.
+    pub const fn error_code(self, error_code: bool) -> Self {
+        Self { error_code, ..self }
+    }
+
+    /// Prints the attached help messages and notes if `help` is `true`, which
+    /// only takes effect in pretty reports.
+    pub const fn help(self, help: bool) -> Self {
+        Self { help, ..self }
+    }
+
+    /// Prints the backtrace if `backtrace` is `true`.
+    pub const fn backtrace(self, backtrace: bool) -> Self {
+        Self { backtrace, ..self }
+    }
+
+    /// Prints the span trace if `span_trace` is `true`, which is only
+    /// available with the `tracing` feature enabled.
+    #[cfg(feature = "tracing")]
+    pub const fn span_trace(self, span_trace: bool) -> Self {
+        Self { span_trace, ..self }
+    }
+
+    /// Prints the attached context if `context` is `true`.
+    pub const fn context(self, context: bool) -> Self {
+        Self { context, ..self }
+    }
+
+    /// Merges context entries sharing the same key according to
+    /// `merge_policy`, which shows all entries by default.
+    pub const fn merge_policy(self, merge_policy: MergePolicy) -> Self {
+        Self {
+            merge_policy,
+            ..self
+        }
+    }
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Renderer<'a, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    error: &'a AnyError<C, K>,
+    options: &'a ReportOptions,
+    merged: Option<MergedContext<'a, C::Entry>>,
+    fmt_entry: fn(&C::Entry, &mut Formatter<'_>) -> FmtResult,
+}
+
+impl<'a, C, K> Renderer<'a, C, K>
+where
+    C: AbstractContext<Entry: Display>,
+    K: Kind,
+{
+    pub fn new(error: &'a AnyError<C, K>, options: &'a ReportOptions) -> Self {
+        Self::with_entry_formatter(error, options, Display::fmt)
+    }
+}
+
+impl<'a, C, K> Renderer<'a, C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    /// Creates a [`Renderer`] which doesn't require entries to implement
+    /// [`Display`], and prints them with [`Entry::fmt_entry()`] instead.
+    pub fn with_fmt_entry(error: &'a AnyError<C, K>, options: &'a ReportOptions) -> Self {
+        Self::with_entry_formatter(error, options, Entry::fmt_entry)
+    }
+
+    fn with_entry_formatter(
+        error: &'a AnyError<C, K>,
+        options: &'a ReportOptions,
+        fmt_entry: fn(&C::Entry, &mut Formatter<'_>) -> FmtResult,
+    ) -> Self {
+        let merged = match options.merge_policy {
+            MergePolicy::All => None,
+            policy => Some(error.merged_context_with(policy)),
+        };
+        Self {
+            error,
+            options,
+            merged,
+            fmt_entry,
+        }
+    }
+
+    pub fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.options.pretty {
+            self.render_pretty_report(f)
+        } else {
+            self.render_compact_report(f)
+        }
+    }
+
+    fn render_pretty_report(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.error_code() {
+            Some(code) => {
+                let prefix = format!("Error[{}]:", code.code());
+                self.render_single_pretty_error(f, &prefix, self.error)?;
+            }
+            None => self.render_single_pretty_error(f, "Error:", self.error)?,
+        }
+
+        let mut source = self.error.source();
+        loop {
+            let error = source.and_then(|error| error.downcast_ref::<AnyError<C, K>>());
+            if let Some(error) = error {
+                self.render_single_pretty_error(f, "Caused by:", error)?;
+                source = error.source();
+            } else {
+                break;
+            }
+        }
+
+        self.render_helps_and_notes(f)?;
+        self.render_help_url(f)?;
+        #[cfg(feature = "tracing")]
+        self.render_span_trace(f)?;
+        self.render_backtrace(f)?;
+        Ok(())
+    }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        self.options
+            .error_code
+            .then(|| self.error.error_code())
+            .flatten()
+    }
+
+    fn render_single_pretty_error(
+        &self,
+        f: &mut Formatter<'_>,
+        prefix: &str,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        writeln!(f, "{}", prefix)?;
+        self.render_pretty_single_error_message(f, error)?;
+        self.render_pretty_one_line_context(f, error)?;
+        self.render_pretty_source_spans(f, error)?;
+        Ok(())
+    }
+
+    fn render_pretty_single_error_message(
+        &self,
+        f: &mut Formatter<'_>,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        write!(f, "    ")?;
+        if self.options.kind {
+            writeln!(f, "({}) {}", error.kind(), error)
+        } else {
+            writeln!(f, "{}", error)
+        }
+    }
+
+    fn render_pretty_one_line_context(
+        &self,
+        f: &mut Formatter<'_>,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        if !self.options.context {
+            return Ok(());
+        }
+        let mut context = error
+            .context(ContextDepth::Shallowest)
+            .filter(|entry| self.is_visible(entry))
+            .peekable();
+        if context.peek().is_none() {
+            return Ok(());
+        }
+        write!(f, "    [")?;
+        let mut first = true;
+        for entry in context {
+            if !first {
+                write!(f, ", ")?;
+            }
+            (self.fmt_entry)(entry, f)?;
+            first = false;
+        }
+        writeln!(f, "]")?;
+        Ok(())
+    }
+
+    fn render_pretty_source_spans(
+        &self,
+        f: &mut Formatter<'_>,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        for span in error.layer_source_spans() {
+            for line in span.to_string().lines() {
+                writeln!(f, "    {line}")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_visible(&self, entry: &C::Entry) -> bool {
+        self.merged
+            .as_ref()
+            .map_or(true, |merged| merged.contains(entry))
+    }
+
+    fn render_helps_and_notes(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.options.help {
+            return Ok(());
+        }
+        let helps = self.error.helps().map(|help| ("help", help));
+        let notes = self.error.notes().map(|note| ("note", note));
+        let mut lines = helps.chain(notes).peekable();
+        if lines.peek().is_none() {
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        for (label, line) in lines {
+            writeln!(f, "{label}: {line}")?;
+        }
+        Ok(())
+    }
+
+    fn render_help_url(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Some(code) = self.error_code() else {
+            return Ok(());
+        };
+        let Some(help_url) = code.help_url() else {
+            return Ok(());
+        };
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "For more information about {}, see {}",
+            code.code(),
+            help_url
+        )?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    fn render_span_trace(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let span_trace = self.error.span_trace();
+        if !self.options.span_trace || span_trace.status() != SpanTraceStatus::CAPTURED {
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        writeln!(f, "In spans:")?;
+        writeln!(f, "{}", span_trace)?;
+        Ok(())
+    }
+
+    fn render_backtrace(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.options.backtrace {
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Stack backtrace:")?;
+        writeln!(f, "{}", self.error.backtrace())?;
+        Ok(())
+    }
+
+    fn render_compact_report(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(code) = self.error_code() {
+            write!(f, "[{}] ", code.code())?;
+        }
+        self.render_one_line_message(f)?;
+        self.render_compact_one_line_context(f, self.error)?;
+        Ok(())
+    }
+
+    fn render_one_line_message(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.render_compact_single_error_message(f, self.error)?;
+        let mut source = self.error.source();
+        loop {
+            let error = source.and_then(|error| error.downcast_ref::<AnyError<C, K>>());
+            if let Some(error) = error {
+                write!(f, ": ")?;
+                self.render_compact_single_error_message(f, error)?;
+                source = error.source();
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_compact_one_line_context(
+        &self,
+        f: &mut Formatter<'_>,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        if !self.options.context {
+            return Ok(());
+        }
+        let mut context = error
+            .context(ContextDepth::All)
+            .filter(|entry| self.is_visible(entry))
+            .peekable();
+        if context.peek().is_none() {
+            return Ok(());
+        }
+        write!(f, " ")?;
+        write!(f, "[")?;
+        let mut first = true;
+        for entry in context {
+            if !first {
+                write!(f, ", ")?;
+            }
+            (self.fmt_entry)(entry, f)?;
+            first = false;
+        }
+        write!(f, "]")?;
+        Ok(())
+    }
+
+    fn render_compact_single_error_message(
+        &self,
+        f: &mut Formatter<'_>,
+        error: &AnyError<C, K>,
+    ) -> FmtResult {
+        if self.options.kind {
+            write!(f, "({}) {}", error.kind(), error)?;
+        } else {
+            write!(f, "{}", error)?;
+        }
+        for span in error.layer_source_spans() {
+            let (line, column) = span.location();
+            let range = span.range();
+            write!(
+                f,
+                " (at {}:{line}:{column}, bytes {}..{})",
+                span.source_name(),
+                range.start,
+                range.end
+            )?;
+        }
+        Ok(())
+    }
+}
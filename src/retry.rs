@@ -0,0 +1,495 @@
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hash::BuildHasher;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::context::{AbstractContext, Context};
+use crate::converter::Convertable;
+use crate::kind::Kind;
+use crate::AnyError;
+
+/// The policy which decides how long to wait before the next attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Waits for the same duration after each attempt.
+    Fixed(Duration),
+    /// Waits for `initial` after the first attempt, and the delay is
+    /// multiplied by `factor` after each following attempt, but never exceeds
+    /// `max`.
+    Exponential {
+        initial: Duration,
+        factor: u32,
+        max: Duration,
+    },
+    /// Waits for a random duration between zero and the delay computed as
+    /// [`Backoff::Exponential`] does, which spreads out the attempts of
+    /// concurrent callers.
+    ///
+    /// The randomness comes from hashing the attempt number with a freshly
+    /// seeded [`RandomState`] to avoid depending on a random number generator.
+    /// It's good enough to spread out retries, but it's neither uniformly
+    /// distributed nor cryptographically secure.
+    Jittered {
+        initial: Duration,
+        factor: u32,
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Returns the delay after the `attempt`-th attempt, which starts from 1.
+    pub fn delay(&self, attempt: usize) -> Duration {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Exponential {
+                initial,
+                factor,
+                max,
+            } => Self::exponential(initial, factor, max, attempt),
+            Self::Jittered {
+                initial,
+                factor,
+                max,
+            } => {
+                let bound = Self::exponential(initial, factor, max, attempt);
+                let random = RandomState::new().hash_one(attempt);
+                bound.mul_f64(random as f64 / u64::MAX as f64)
+            }
+        }
+    }
+
+    fn exponential(initial: Duration, factor: u32, max: Duration, attempt: usize) -> Duration {
+        let exponent = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        initial
+            .checked_mul(factor.saturating_pow(exponent))
+            .map_or(max, |delay| delay.min(max))
+    }
+}
+
+/// The source of time used by [`Retry`], which can be replaced in tests.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Blocks the current thread for `duration`.
+    fn sleep(&self, duration: Duration);
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration)
+    }
+}
+
+/// The [`Clock`] backed by the system's monotonic clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// A helper which runs a fallible operation until it succeeds, driven by the
+/// kinds of the errors it returns.
+///
+/// After each failed attempt, the operation is retried only if the error
+/// [`is_retryable()`], the maximum number of attempts is not reached, and the
+/// next attempt would start before the deadline. Otherwise, all errors are
+/// aggregated into an [`Attempts`] wrapped in an [`AnyError`], whose kind is
+/// taken from the error of the last attempt, and whose context records the
+/// number of attempts with the key `"attempts"` and the elapsed time in
+/// milliseconds with the key `"elapsed_ms"`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use anyerr::AnyError as AnyErrorTemplate;
+/// # use anyerr::context::LiteralKeyStringMapContext;
+/// # use anyerr::kind::DefaultErrorKind;
+/// # use anyerr::retry::{Attempts, Backoff, Retry};
+/// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+///
+/// let mut calls = 0;
+/// let res = Retry::new(Backoff::Fixed(Duration::from_millis(1)))
+///     .max_attempts(3)
+///     .run(|| {
+///         calls += 1;
+///         Err::<(), _>(AnyError::quick("timed out", DefaultErrorKind::InfrastructureFailure))
+///     });
+///
+/// let err = res.unwrap_err();
+/// assert_eq!(calls, 3);
+/// assert_eq!(err.get("attempts"), Some("3"));
+/// let attempts = err
+///     .downcast_ref::<Attempts<LiteralKeyStringMapContext, DefaultErrorKind>>()
+///     .unwrap();
+/// assert_eq!(attempts.errors().len(), 3);
+/// ```
+///
+/// [`is_retryable()`]: crate::AnyError::is_retryable
+#[derive(Debug, Clone)]
+pub struct Retry<Cl = SystemClock> {
+    backoff: Backoff,
+    max_attempts: usize,
+    deadline: Option<Duration>,
+    clock: Cl,
+}
+
+impl Retry {
+    /// Creates a [`Retry`] with the given backoff policy, which makes at most
+    /// 3 attempts without a deadline.
+    pub fn new(backoff: Backoff) -> Self {
+        Self {
+            backoff,
+            max_attempts: 3,
+            deadline: None,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<Cl: Clock> Retry<Cl> {
+    /// Makes at most `max_attempts` attempts, including the first one. At
+    /// least one attempt is always made.
+    pub fn max_attempts(self, max_attempts: usize) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..self
+        }
+    }
+
+    /// Gives up if the next attempt would start later than `deadline` after
+    /// the first one.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    /// Replaces the source of time.
+    pub fn clock<T: Clock>(self, clock: T) -> Retry<T> {
+        Retry {
+            backoff: self.backoff,
+            max_attempts: self.max_attempts,
+            deadline: self.deadline,
+            clock,
+        }
+    }
+
+    /// Runs `operation` until it succeeds or no more attempts are allowed.
+    pub fn run<T, C, K, F>(&self, mut operation: F) -> Result<T, AnyError<C, K>>
+    where
+        C: Context,
+        K: Kind,
+        F: FnMut() -> Result<T, AnyError<C, K>>,
+        &'static str: Into<C::Key>,
+        usize: Convertable<C::Converter, C::Value>,
+        u64: Convertable<C::Converter, C::Value>,
+    {
+        let start = self.clock.now();
+        let mut errors = Vec::new();
+        loop {
+            let err = match operation() {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            let retryable = err.is_retryable();
+            errors.push(err);
+
+            let attempt = errors.len();
+            let delay = self.backoff.delay(attempt);
+            let elapsed = self.clock.now().duration_since(start);
+            let expired = self
+                .deadline
+                .is_some_and(|deadline| elapsed.saturating_add(delay) > deadline);
+            if !retryable || attempt >= self.max_attempts || expired {
+                return Err(Attempts::aggregate(errors, elapsed));
+            }
+            self.clock.sleep(delay);
+        }
+    }
+}
+
+/// The error made by [`Retry`] when it gives up, which holds the errors of
+/// all attempts.
+///
+/// Its [`Error::source()`] is the error of the last attempt, so that it's
+/// shown as the cause in error reports.
+#[derive(Debug)]
+pub struct Attempts<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    errors: Vec<AnyError<C, K>>,
+    elapsed: Duration,
+}
+
+impl<C, K> Attempts<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    /// Returns the errors of all attempts in order.
+    pub fn errors(&self) -> &[AnyError<C, K>] {
+        &self.errors
+    }
+
+    /// Returns the error of the last attempt.
+    pub fn last(&self) -> &AnyError<C, K> {
+        self.errors
+            .last()
+            .expect("at least one attempt should have been made")
+    }
+
+    /// Returns the time elapsed from the first attempt to the last one.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Consumes this error and returns the errors of all attempts.
+    pub fn into_errors(self) -> Vec<AnyError<C, K>> {
+        self.errors
+    }
+
+    fn aggregate(errors: Vec<AnyError<C, K>>, elapsed: Duration) -> AnyError<C, K>
+    where
+        C: Context,
+        &'static str: Into<C::Key>,
+        usize: Convertable<C::Converter, C::Value>,
+        u64: Convertable<C::Converter, C::Value>,
+    {
+        let attempts = errors.len();
+        let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        let kind = errors.last().map_or(K::RAW_KIND, |last| {
+            last.specified_kind().unwrap_or_else(|| last.kind())
+        });
+        AnyError::wrap_with_kind(Self { errors, elapsed }, kind)
+            .attach_context("attempts", attempts)
+            .attach_context("elapsed_ms", elapsed_ms)
+    }
+}
+
+impl<C, K> Display for Attempts<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.errors.len() {
+            1 => write!(f, "the only attempt failed"),
+            n => write!(f, "all {n} attempts failed"),
+        }
+    }
+}
+
+impl<C, K> Error for Attempts<C, K>
+where
+    C: AbstractContext,
+    K: Kind,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.last())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use crate::context::LiteralKeyStringMapContext;
+    use crate::kind::DefaultErrorKind;
+    use crate::{Intermediate, Overlay};
+
+    use super::*;
+
+    type TestError = AnyError<LiteralKeyStringMapContext, DefaultErrorKind>;
+
+    struct TestClock {
+        start: Instant,
+        offset: Cell<Duration>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl TestClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                offset: Cell::new(Duration::ZERO),
+                sleeps: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            self.offset.set(self.offset.get() + duration);
+        }
+    }
+
+    impl Clock for TestClock {
+        fn now(&self) -> Instant {
+            self.start + self.offset.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+            self.advance(duration);
+        }
+    }
+
+    fn transient() -> TestError {
+        TestError::quick("transient", DefaultErrorKind::InfrastructureFailure)
+    }
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn backoff_delay_succeeds() {
+        let backoff = Backoff::Fixed(millis(10));
+        assert_eq!(backoff.delay(1), millis(10));
+        assert_eq!(backoff.delay(5), millis(10));
+
+        let backoff = Backoff::Exponential {
+            initial: millis(10),
+            factor: 2,
+            max: millis(50),
+        };
+        assert_eq!(backoff.delay(1), millis(10));
+        assert_eq!(backoff.delay(2), millis(20));
+        assert_eq!(backoff.delay(3), millis(40));
+        assert_eq!(backoff.delay(4), millis(50));
+        assert_eq!(backoff.delay(usize::MAX), millis(50));
+
+        let backoff = Backoff::Jittered {
+            initial: millis(10),
+            factor: 2,
+            max: millis(50),
+        };
+        for attempt in 1..10 {
+            assert!(backoff.delay(attempt) <= millis(50));
+        }
+        assert!(backoff.delay(1) <= millis(10));
+    }
+
+    #[test]
+    fn retry_run_succeeds_after_failures() {
+        let clock = TestClock::new();
+        let mut calls = 0;
+        let res = Retry::new(Backoff::Fixed(millis(10)))
+            .max_attempts(5)
+            .clock(&clock)
+            .run(|| {
+                calls += 1;
+                if calls < 3 {
+                    Err(transient())
+                } else {
+                    Ok(calls)
+                }
+            });
+        assert_eq!(res.unwrap(), 3);
+        assert_eq!(*clock.sleeps.borrow(), [millis(10), millis(10)]);
+    }
+
+    #[test]
+    fn retry_run_aggregates_errors_when_all_attempts_fail() {
+        let clock = TestClock::new();
+        let mut calls = 0;
+        let err = Retry::new(Backoff::Exponential {
+            initial: millis(10),
+            factor: 2,
+            max: millis(100),
+        })
+        .max_attempts(3)
+        .clock(&clock)
+        .run(|| {
+            calls += 1;
+            clock.advance(millis(1));
+            Err::<(), _>(transient().attach_context("call", calls))
+        })
+        .unwrap_err();
+
+        assert_eq!(*clock.sleeps.borrow(), [millis(10), millis(20)]);
+        assert_eq!(err.to_string(), "all 3 attempts failed");
+        assert_eq!(err.kind(), DefaultErrorKind::InfrastructureFailure);
+        assert!(err.is_retryable());
+        assert_eq!(err.get("attempts"), Some("3"));
+        assert_eq!(err.get("elapsed_ms"), Some("33"));
+
+        let attempts = err
+            .downcast_ref::<Attempts<LiteralKeyStringMapContext, DefaultErrorKind>>()
+            .unwrap();
+        assert_eq!(attempts.errors().len(), 3);
+        assert_eq!(attempts.elapsed(), millis(33));
+        assert_eq!(attempts.last().get("call"), Some("3"));
+        assert_eq!(err.source().unwrap().to_string(), "transient");
+    }
+
+    #[test]
+    fn retry_run_stops_on_non_retryable_errors() {
+        let mut calls = 0;
+        let err = Retry::new(Backoff::Fixed(millis(10)))
+            .clock(TestClock::new())
+            .run(|| {
+                calls += 1;
+                Err::<(), _>(TestError::quick(
+                    "invalid",
+                    DefaultErrorKind::ValueValidation,
+                ))
+            })
+            .unwrap_err();
+        assert_eq!(calls, 1);
+        assert_eq!(err.to_string(), "the only attempt failed");
+        assert_eq!(err.get("attempts"), Some("1"));
+        assert_eq!(err.kind(), DefaultErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn retry_run_propagates_specified_kind_of_last_error() {
+        let err = Retry::new(Backoff::Fixed(millis(10)))
+            .clock(TestClock::new())
+            .run(|| {
+                Err::<(), _>(
+                    TestError::quick("absent", DefaultErrorKind::EntityAbsence)
+                        .overlay("could not load the user")
+                        .build(),
+                )
+            })
+            .unwrap_err();
+        assert_eq!(err.kind(), DefaultErrorKind::EntityAbsence);
+        assert_eq!(err.specified_kind(), Some(DefaultErrorKind::EntityAbsence));
+    }
+
+    #[test]
+    fn retry_run_stops_at_deadline() {
+        let clock = TestClock::new();
+        let mut calls = 0;
+        let err = Retry::new(Backoff::Fixed(millis(10)))
+            .max_attempts(10)
+            .deadline(millis(25))
+            .clock(&clock)
+            .run(|| {
+                calls += 1;
+                Err::<(), _>(transient())
+            })
+            .unwrap_err();
+        assert_eq!(calls, 3);
+        assert_eq!(*clock.sleeps.borrow(), [millis(10), millis(10)]);
+        assert_eq!(err.get("attempts"), Some("3"));
+        assert_eq!(err.get("elapsed_ms"), Some("20"));
+    }
+}
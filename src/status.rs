@@ -0,0 +1,272 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::context::AbstractContext;
+use crate::kind::{DefaultErrorKind, Kind, NoErrorKind};
+use crate::AnyError;
+
+/// The canonical status codes used by gRPC and other RPC systems.
+///
+/// Each variant corresponds to the code of the same name and value in the
+/// [gRPC specification], and its [`Display`] representation is the name used
+/// there, such as `INVALID_ARGUMENT`.
+///
+/// [gRPC specification]: https://grpc.github.io/grpc/core/md_doc_statuscodes.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Code {
+    /// Not an error.
+    Ok = 0,
+    /// The operation was cancelled, typically by the caller.
+    Cancelled = 1,
+    /// An error which doesn't fit into any other code.
+    #[default]
+    Unknown = 2,
+    /// The client specified an invalid argument.
+    InvalidArgument = 3,
+    /// The deadline expired before the operation could complete.
+    DeadlineExceeded = 4,
+    /// Some requested entity was not found.
+    NotFound = 5,
+    /// The entity that a client attempted to create already exists.
+    AlreadyExists = 6,
+    /// The caller doesn't have permission to execute the operation.
+    PermissionDenied = 7,
+    /// Some resource has been exhausted.
+    ResourceExhausted = 8,
+    /// The system is not in a state required for the operation's execution.
+    FailedPrecondition = 9,
+    /// The operation was aborted, typically due to a concurrency issue.
+    Aborted = 10,
+    /// The operation was attempted past the valid range.
+    OutOfRange = 11,
+    /// The operation is not implemented or supported.
+    Unimplemented = 12,
+    /// Some invariants expected by the underlying system have been broken.
+    Internal = 13,
+    /// The service is currently unavailable.
+    Unavailable = 14,
+    /// Unrecoverable data loss or corruption.
+    DataLoss = 15,
+    /// The request doesn't have valid authentication credentials.
+    Unauthenticated = 16,
+}
+
+impl Code {
+    /// Returns the code whose numeric value is `value`, or `None` if there's
+    /// no such code.
+    pub fn from_i32(value: i32) -> Option<Self> {
+        let code = match value {
+            0 => Self::Ok,
+            1 => Self::Cancelled,
+            2 => Self::Unknown,
+            3 => Self::InvalidArgument,
+            4 => Self::DeadlineExceeded,
+            5 => Self::NotFound,
+            6 => Self::AlreadyExists,
+            7 => Self::PermissionDenied,
+            8 => Self::ResourceExhausted,
+            9 => Self::FailedPrecondition,
+            10 => Self::Aborted,
+            11 => Self::OutOfRange,
+            12 => Self::Unimplemented,
+            13 => Self::Internal,
+            14 => Self::Unavailable,
+            15 => Self::DataLoss,
+            16 => Self::Unauthenticated,
+            _ => return None,
+        };
+        Some(code)
+    }
+
+    /// Returns the numeric value of the code.
+    pub fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns true if the code is [`Code::Ok`].
+    pub fn is_ok(self) -> bool {
+        self == Self::Ok
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let value = match self {
+            Self::Ok => "OK",
+            Self::Cancelled => "CANCELLED",
+            Self::Unknown => "UNKNOWN",
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            Self::NotFound => "NOT_FOUND",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Self::FailedPrecondition => "FAILED_PRECONDITION",
+            Self::Aborted => "ABORTED",
+            Self::OutOfRange => "OUT_OF_RANGE",
+            Self::Unimplemented => "UNIMPLEMENTED",
+            Self::Internal => "INTERNAL",
+            Self::Unavailable => "UNAVAILABLE",
+            Self::DataLoss => "DATA_LOSS",
+            Self::Unauthenticated => "UNAUTHENTICATED",
+        };
+        write!(f, "{value}")
+    }
+}
+
+impl From<Code> for i32 {
+    fn from(code: Code) -> Self {
+        code.as_i32()
+    }
+}
+
+/// An error kind which can be converted from and to a status [`Code`].
+pub trait StatusKind: Kind {
+    /// Returns the status code of this kind of error, which is
+    /// [`Code::Unknown`] by default.
+    fn code(&self) -> Code {
+        Code::Unknown
+    }
+
+    /// Returns the kind which best describes errors of the status `code`,
+    /// which is [`Kind::UNKNOWN_KIND`] by default.
+    fn from_code(code: Code) -> Self {
+        let _ = code;
+        Self::UNKNOWN_KIND
+    }
+}
+
+impl StatusKind for DefaultErrorKind {
+    fn code(&self) -> Code {
+        match self {
+            Self::ValueValidation => Code::InvalidArgument,
+            Self::RuleViolation => Code::FailedPrecondition,
+            Self::EntityAbsence => Code::NotFound,
+            Self::InfrastructureFailure => Code::Unavailable,
+            Self::Raw | Self::Unknown => Code::Unknown,
+        }
+    }
+
+    fn from_code(code: Code) -> Self {
+        match code {
+            Code::InvalidArgument | Code::OutOfRange => Self::ValueValidation,
+            Code::FailedPrecondition | Code::AlreadyExists | Code::Aborted => Self::RuleViolation,
+            Code::NotFound => Self::EntityAbsence,
+            Code::Unavailable | Code::DeadlineExceeded | Code::ResourceExhausted => {
+                Self::InfrastructureFailure
+            }
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl StatusKind for NoErrorKind {}
+
+impl<C, K> AnyError<C, K>
+where
+    C: AbstractContext,
+    K: StatusKind,
+{
+    /// Makes an [`AnyError`] with the given error message, whose kind is the
+    /// one which best describes errors of the status `code`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use anyerr::AnyError as AnyErrorTemplate;
+    /// # use anyerr::context::LiteralKeyStringMapContext;
+    /// # use anyerr::kind::DefaultErrorKind;
+    /// # use anyerr::status::Code;
+    /// type AnyError = AnyErrorTemplate<LiteralKeyStringMapContext, DefaultErrorKind>;
+    /// let err = AnyError::from_status(Code::NotFound, "user 42 was not found");
+    /// assert_eq!(err.kind(), DefaultErrorKind::EntityAbsence);
+    /// assert_eq!(err.code(), Code::NotFound);
+    /// ```
+    pub fn from_status<S: Into<String>>(code: Code, message: S) -> Self {
+        Self::quick(message, K::from_code(code))
+    }
+
+    /// Returns the status code of this error.
+    ///
+    /// The kinds along the error chain are checked from the outermost layer
+    /// to the innermost one, and the first kind which is neither
+    /// [`Kind::RAW_KIND`] nor [`Kind::UNKNOWN_KIND`] decides the status code.
+    /// [`Code::Unknown`] is returned if no such kind is found.
+    pub fn code(&self) -> Code {
+        self.specified_kind()
+            .map_or(Code::Unknown, |kind| kind.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::LiteralKeyStringMapContext;
+    use crate::{Intermediate, Overlay};
+
+    use super::*;
+
+    type TestError = AnyError<LiteralKeyStringMapContext, DefaultErrorKind>;
+
+    #[test]
+    fn code_conversion_succeeds() {
+        for value in 0..=16 {
+            let code = Code::from_i32(value).unwrap();
+            assert_eq!(i32::from(code), value);
+        }
+        assert_eq!(Code::from_i32(17), None);
+        assert_eq!(Code::from_i32(-1), None);
+        assert_eq!(Code::InvalidArgument.to_string(), "INVALID_ARGUMENT");
+        assert!(Code::Ok.is_ok());
+    }
+
+    #[test]
+    fn status_kind_round_trip_succeeds() {
+        let kinds = [
+            DefaultErrorKind::ValueValidation,
+            DefaultErrorKind::RuleViolation,
+            DefaultErrorKind::EntityAbsence,
+            DefaultErrorKind::InfrastructureFailure,
+            DefaultErrorKind::Unknown,
+        ];
+        for kind in kinds {
+            assert_eq!(DefaultErrorKind::from_code(kind.code()), kind);
+        }
+        assert_eq!(
+            DefaultErrorKind::from_code(Code::DeadlineExceeded),
+            DefaultErrorKind::InfrastructureFailure
+        );
+        assert_eq!(
+            DefaultErrorKind::from_code(Code::Internal),
+            DefaultErrorKind::Unknown
+        );
+    }
+
+    #[test]
+    fn any_error_code_succeeds() {
+        let err = TestError::wrap(std::fmt::Error);
+        assert_eq!(err.code(), Code::Unknown);
+
+        let err = TestError::from_status(Code::Unavailable, "service is down")
+            .overlay("could not load the user")
+            .build();
+        assert_eq!(err.kind(), DefaultErrorKind::Unknown);
+        assert_eq!(err.code(), Code::Unavailable);
+        assert!(err.is_retryable());
+
+        let err = TestError::from_status(Code::AlreadyExists, "user exists");
+        assert_eq!(err.kind(), DefaultErrorKind::RuleViolation);
+        assert_eq!(err.code(), Code::FailedPrecondition);
+    }
+
+    #[test]
+    fn any_error_code_is_independent_of_error_code() {
+        const E1042: crate::code::ErrorCode =
+            crate::code::ErrorCode::new("E1042", "the account is locked");
+        let err = TestError::builder()
+            .message("account 42 is locked")
+            .kind(DefaultErrorKind::RuleViolation)
+            .error_code(E1042)
+            .build();
+        assert_eq!(err.code(), Code::FailedPrecondition);
+        assert_eq!(err.error_code(), Some(E1042));
+    }
+}